@@ -1,7 +1,7 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use absagl::groups::{FiniteGroup, modulo::Modulo, Additive, Group};
+use absagl::groups::{FiniteGroup, modulo::Modulo, permutation::Permutation, Additive, Group};
 
-fn bench_is_closed(c: &mut Criterion) {
+fn bench_is_closed(_c: &mut Criterion) {
     let n = 500;
     let elements = Modulo::<Additive>::generate_group(n).unwrap();
     let group = FiniteGroup::new(elements);
@@ -14,5 +14,15 @@ fn bench_is_closed(c: &mut Criterion) {
     config.bench_function("is_closed_parallel", |b| b.iter(|| group.is_closed_parallel()));
 }
 
-criterion_group!(benches, bench_is_closed);
+fn bench_generate_symmetric(_c: &mut Criterion) {
+    let n = 8;
+    let mut config = Criterion::default()
+        .sample_size(10)
+        .measurement_time(std::time::Duration::from_secs(1));
+
+    config.bench_function("generate_group_s8", |b| b.iter(|| Permutation::generate_group(n).unwrap()));
+    config.bench_function("generate_group_parallel_s8", |b| b.iter(|| Permutation::generate_group_parallel(n).unwrap()));
+}
+
+criterion_group!(benches, bench_is_closed, bench_generate_symmetric);
 criterion_main!(benches);
\ No newline at end of file
@@ -1,35 +1,9 @@
 
 
-use absagl::error::AbsaglError;
-use absagl::groups::dihedral::DihedralElement;
-use absagl::groups::factor::Coset;
-use absagl::groups::factor::CosetError;
-use absagl::groups::factor::FactorGroup;
-use absagl::groups::permutation::AlternatingGroupElement;
-use absagl::groups::permutation::PermutationError;
-use absagl::groups::CanonicalRepr;
-use absagl::groups::CheckedOp;
-use absagl::groups::Group;
-use absagl::groups::GroupGenerators;
 use absagl::groups::GroupElement;
 use absagl::groups::permutation::Permutation;
-use absagl::groups::modulo::Modulo;
-use absagl::groups::FiniteGroup;
-use absagl::groups::Multiplicative;
-use absagl::groups::{Additive};
-use absagl::utils;
-use absagl::show;
-use absagl::homomorphism::Homomorphism;
-use absagl::groups::factor::CosetSide;
-use rayon::result;
-use rayon::vec;
 
 
-use std::marker::PhantomData;
-use std::collections::HashMap;
-use std::iter::Cycle;
-use std::pin;
-use log::{info, warn, error, debug};
 
 
 
@@ -39,7 +13,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     
     let n = 3;
-    let sigma = Permutation::from_cycles(&vec![vec![0,1]], n)?;
+    let sigma = Permutation::from_cycles(&[vec![0,1]], n)?;
     let tau = Permutation::from_cycles(&[(0..n).collect::<Vec<_>>()], n)?;
     let tau_inverse = tau.inverse();
 
@@ -52,7 +26,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("result: {}", result);
 
     let n = 4;
-    let sigma = Permutation::from_cycles(&vec![vec![0,1]], n)?;
+    let sigma = Permutation::from_cycles(&[vec![0,1]], n)?;
     let tau = Permutation::from_cycles(&[(0..n).collect::<Vec<_>>()], n)?;
     let tau_inverse = tau.inverse();
 
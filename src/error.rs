@@ -11,10 +11,13 @@ pub enum AbsaglError {
     Modulo(crate::groups::modulo::ModuloError),
     Permutation(crate::groups::permutation::PermutationError),
     Dihedral(crate::groups::dihedral::DihedralError),
+    Dicyclic(crate::groups::dicyclic::DicyclicError),
+    CayleyTable(crate::groups::cayley::CayleyTableError),
     Group(crate::groups::GroupError),
     Coset(crate::groups::factor::CosetError),
     Homomorphism(crate::homomorphism::HomomorphismError),
     Ring(crate::rings::RingError),
+    DirectProduct(crate::groups::directproduct::DirectProductError),
     // this new variant to hold the generic error from T
     Element(Box<dyn Error + Send + Sync + 'static>),
     // ... add other sub-errors
@@ -27,10 +30,13 @@ impl fmt::Display for AbsaglError {
             AbsaglError::Modulo(e) => write!(f, "Modulo error: {}", e),
             AbsaglError::Permutation(e) => write!(f, "Permutation error: {}", e),
             AbsaglError::Dihedral(e) => write!(f, "Diherdral error: {}", e),
+            AbsaglError::Dicyclic(e) => write!(f, "Dicyclic error: {}", e),
+            AbsaglError::CayleyTable(e) => write!(f, "Cayley table error: {}", e),
             AbsaglError::Group(e) => write!(f, "Group error: {}", e),
             AbsaglError::Coset(e) => write!(f, "Coset error: {}", e),
             AbsaglError::Homomorphism(e) => write!(f, "Homomorphism error: {}", e),
             AbsaglError::Ring(e) => write!(f, "Ring error: {}", e),
+            AbsaglError::DirectProduct(e) => write!(f, "Direct product error: {}", e),
             AbsaglError::Element(e) => write!(f, "Underlying element error: {}", e),
             AbsaglError::Other(msg) => write!(f, "Other error: {}", msg),
         }
@@ -43,10 +49,13 @@ impl std::error::Error for AbsaglError {
             AbsaglError::Modulo(e) => Some(e),
             AbsaglError::Permutation(e) => Some(e),
             AbsaglError::Dihedral(e) => Some(e),
+            AbsaglError::Dicyclic(e) => Some(e),
+            AbsaglError::CayleyTable(e) => Some(e),
             AbsaglError::Group(e) => Some(e),
             AbsaglError::Coset(e) => Some(e),
             AbsaglError::Homomorphism(e) => Some(e),
             AbsaglError::Ring(e) => Some(e),
+            AbsaglError::DirectProduct(e) => Some(e),
             AbsaglError::Element(e) => Some(e.as_ref()),
             AbsaglError::Other(_) => None,
         }
@@ -85,6 +94,18 @@ impl From<crate::groups::dihedral::DihedralError> for AbsaglError {
     }
 }
 
+impl From<crate::groups::dicyclic::DicyclicError> for AbsaglError {
+    fn from(e: crate::groups::dicyclic::DicyclicError) -> Self {
+        AbsaglError::Dicyclic(e)
+    }
+}
+
+impl From<crate::groups::cayley::CayleyTableError> for AbsaglError {
+    fn from(e: crate::groups::cayley::CayleyTableError) -> Self {
+        AbsaglError::CayleyTable(e)
+    }
+}
+
 impl From<crate::homomorphism::HomomorphismError> for AbsaglError {
     fn from(e: crate::homomorphism::HomomorphismError) -> Self {
         AbsaglError::Homomorphism(e)
@@ -97,6 +118,19 @@ impl From<crate::rings::RingError> for AbsaglError {
     }
 }
 
+impl From<crate::groups::directproduct::DirectProductError> for AbsaglError {
+    fn from(e: crate::groups::directproduct::DirectProductError) -> Self {
+        AbsaglError::DirectProduct(e)
+    }
+}
+
+// for element types whose checked operation can never fail, e.g. QuaternionElement
+impl From<std::convert::Infallible> for AbsaglError {
+    fn from(e: std::convert::Infallible) -> Self {
+        match e {}
+    }
+}
+
 impl From<String> for AbsaglError {
     fn from(e:String) -> Self {
         AbsaglError::Other(e)
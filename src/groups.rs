@@ -3,15 +3,22 @@ pub mod permutation;
 pub mod dihedral;
 pub mod factor;
 pub mod directproduct;
+pub mod quaternion;
+pub mod dicyclic;
+pub mod bsgs;
+pub mod cayley;
+pub mod semidirect;
 
 use std::fmt::{self, Debug};
 use std::error::Error;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 
 use crate::error::AbsaglError;
 use crate::utils;
-use crate::groups::directproduct::DirectProductElement;
+use crate::groups::directproduct::{DirectProductElement, DirectProductError};
+use crate::homomorphism::{extend_to_homomorphism, BoxedHomomorphism, Homomorphism};
 
 use rayon::prelude::*;
 
@@ -20,9 +27,14 @@ use rayon::prelude::*;
 pub enum GroupError {
     NotClosed,
     NotAbelian, // this is for abelian group
+    /// The subgroup is the whole group, where a proper subgroup is required.
     NotSubgroup,
     NotNormalSubgroup,
+    /// The candidate has elements outside the group or is not closed, so it is not a subgroup at all.
+    NotContainedOrNotClosed,
+    NotPrime, // e.g. asking for Sylow p-subgroups with a composite p
     NotFound, // this is for identity not found
+    OrderTooLarge, // this is for expensive computations, e.g. enumerating all subgroups
     
     // some operation error
 }
@@ -32,9 +44,12 @@ impl fmt::Display for GroupError {
         match self {
             GroupError::NotClosed => write!(f, "This group is not closed"),
             GroupError::NotAbelian => write!(f, "This group is not abelian"),
-            GroupError::NotSubgroup => write!(f, "The subgroup equal to whole group"),
+            GroupError::NotSubgroup => write!(f, "The subgroup is the whole group, a proper subgroup is required"),
             GroupError::NotNormalSubgroup => write!(f, "The subgroup is not normal subgroup in whole group"),
+            GroupError::NotContainedOrNotClosed => write!(f, "The candidate is not contained in the group or is not closed"),
+            GroupError::NotPrime => write!(f, "The given number is not a prime"),
             GroupError::NotFound => write!(f, "Identity element not found in the group"),
+            GroupError::OrderTooLarge => write!(f, "The group order is too large for this computation"),
             
         }
     }
@@ -116,12 +131,30 @@ pub trait CanonicalRepr {
 }
 
 
+/// The maximum group order accepted by `FiniteGroup::all_subgroups`, e.g. S_5 has order 120.
+pub const MAX_ALL_SUBGROUPS_ORDER: usize = 128;
+
 /// A generic group struct holding elements of type T
 #[derive(Debug, Clone)]
 pub struct FiniteGroup<T: GroupElement> {
     elements: Vec<T>,
 }
 
+/// A membership index over the elements of a group, built by `FiniteGroup::lookup`,
+/// keep it around for repeated `contains` checks in O(1) instead of scanning the elements.
+pub struct GroupLookup<T> {
+    canonical_set: HashSet<Vec<u8>>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: CanonicalRepr> GroupLookup<T> {
+
+    /// Checks if `element` is in the indexed group by looking up its canonical bytes
+    pub fn contains(&self, element: &T) -> bool {
+        self.canonical_set.contains(&element.to_canonical_bytes())
+    }
+}
+
 impl<T: GroupElement> Group<T> for FiniteGroup<T> {
 
       
@@ -157,10 +190,12 @@ impl<T: GroupElement> Group<T> for FiniteGroup<T> {
     /// A group is closed if for all elements i and j in the group, i.op(j) is also in the group.
     /// this is a single-threaded implementation, if you want to use parallel computing, use `is_closed_parallel()`
     fn is_closed(&self) -> bool {
+        // build the lookup set once, so each membership test is O(1) instead of O(n)
+        let element_set: HashSet<&T> = self.elements.iter().collect();
         for i in &self.elements {
             for j in &self.elements {
                 let result = self.operate(i, j);
-                if !self.elements.contains(&result) {
+                if !element_set.contains(&result) {
                     return false;
                 }
             }
@@ -196,6 +231,17 @@ impl<T: GroupElement> FiniteGroup<T> {
         &self.elements
     }
 
+    /// Checks if `element` is in the group, this is a linear scan,
+    /// use `lookup` for repeated checks when `T: CanonicalRepr`.
+    pub fn contains(&self, element: &T) -> bool {
+        self.elements.contains(element)
+    }
+
+    /// Returns an iterator over the elements of the group, in the order of `elements()`.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.elements.iter()
+    }
+
     /// Creates a new group with the given elements, this is unchecked constructor.
     pub fn new(elements: Vec<T>) -> Self {
         FiniteGroup { elements }
@@ -204,7 +250,7 @@ impl<T: GroupElement> FiniteGroup<T> {
     /// Creates a new group with the given elements, this will check if given `Vec<T>` is closed
     pub fn try_new(elements: Vec<T>) -> Result<Self, AbsaglError> {
 
-        let group = FiniteGroup { elements };
+        let group = FiniteGroup::new(elements);
         if !group.is_closed() {
             return Err(GroupError::NotClosed)?;
         }
@@ -214,23 +260,36 @@ impl<T: GroupElement> FiniteGroup<T> {
 
     /// check if a given subgroup is normal in the group
     pub fn is_normal(&self, subgroup: &FiniteGroup<T>) -> bool {
+        let subgroup_set: HashSet<&T> = subgroup.elements.iter().collect();
         for g in &self.elements {
             for h in &subgroup.elements {
                 let conjugate = g.op(h).op(&g.inverse());
-                if !subgroup.elements.contains(&conjugate) {
+                if !subgroup_set.contains(&conjugate) {
                     return false;
                 }
             }
         }
         true
     }
+
+    /// A stricter `is_normal`, it first checks that `candidate` is a subgroup with `is_subgroup`,
+    /// returning `GroupError::NotContainedOrNotClosed` otherwise, then checks that it is closed under conjugation.
+    pub fn is_normal_subgroup(&self, candidate: &FiniteGroup<T>) -> Result<bool, AbsaglError> {
+        if !self.is_subgroup(candidate) {
+            log::error!("The candidate is not a subgroup, cannot check normality");
+            return Err(GroupError::NotContainedOrNotClosed)?;
+        }
+        Ok(self.is_normal(candidate))
+    }
+
     /// Checks if the group is closed in parallel, this is useful for parallel computing.
     /// It checks if for all elements i and j in the group, the result of the
     /// group operation is also in the group.
     pub fn is_closed_parallel(&self) -> bool {
+        let element_set: HashSet<&T> = self.elements.iter().collect();
         self.elements.par_iter().all(|i|
             self.elements.par_iter().all(|j|
-                self.elements.contains(&self.operate(i, j))
+                element_set.contains(&self.operate(i, j))
             )
         )
     }
@@ -301,6 +360,9 @@ impl<T: GroupElement> FiniteGroup<T> {
 
     /// If the group is abelian, computes its decomposition into a direct product
     /// of cyclic groups of prime-power orders.
+    /// the factors are sorted by prime, then by decreasing exponent. for a cyclic group this is just
+    /// the prime factorization of the order, otherwise a prime can appear several times,
+    /// e.g. Z_2 × Z_4 gives `[(2, 2), (2, 1)]` rather than the factorization `[(2, 3)]` of its order.
     pub fn abelian_decomposition(&self) -> Result<AbelianDecomposition, GroupError> {
         if !self.is_abelian() {
             log::error!("The group is not abelian, cannot compute decomposition");
@@ -314,16 +376,602 @@ impl<T: GroupElement> FiniteGroup<T> {
             return Err(GroupError::NotFound);
         }
         
-        // You'll need a prime factorization function.
-        // Let's assume it's in `src/utils.rs`.
-        let prime_factors = utils::prime_factorization(order);
+        // for the p-primary part of order p^a, let c_k be the number of elements whose order divides p^k,
+        // then c_k / c_(k-1) = p^(r_k) where r_k is the number of cyclic factors of order at least p^k.
+        let orders: Vec<u64> = self.elements.iter().map(|g| self.element_order(g) as u64).collect();
+        let mut prime_power_orders = Vec::new();
+        for (p, a) in utils::prime_factorization(order) {
+            let mut at_least = vec![];
+            let mut previous = 1;
+            for k in 1..=a {
+                let count = orders.iter().filter(|&&o| p.pow(k).is_multiple_of(o)).count() as u64;
+                if count == previous {
+                    break;
+                }
+                at_least.push((count / previous).ilog(p));
+                previous = count;
+            }
+            for k in (1..=at_least.len()).rev() {
+                let exactly = at_least[k - 1] - at_least.get(k).copied().unwrap_or(0);
+                prime_power_orders.extend(std::iter::repeat_n((p, k as u32), exactly as usize));
+            }
+        }
+
+        Ok(AbelianDecomposition { prime_power_orders })
+    }
 
-        Ok(AbelianDecomposition {
-            prime_power_orders: prime_factors,
-        })
+    /// Generates the cyclic subgroup `<g>` by repeatedly applying `g.op(...)`,
+    /// starting from the identity until it cycles back.
+    /// if `g` is the identity, the trivial subgroup is returned.
+    pub fn cyclic_subgroup(&self, g: &T) -> FiniteGroup<T> {
+        // g * g⁻¹ gives the identity without searching through the whole group
+        let identity = g.op(&g.inverse());
+
+        let mut elements = vec![identity.clone()];
+        let mut current = g.clone();
+        while current != identity {
+            elements.push(current.clone());
+            current = current.op(g);
+        }
+
+        FiniteGroup::new(elements)
+    }
+
+    /// Returns the order of `g`, the smallest `k > 0` such that `g^k == e`, by iterating `op`.
+    /// this works for any `GroupElement`, e.g. `Coset` or `DirectProductElement` that don't have a native `order()`.
+    /// it will panic if `k` exceeds the order of the group, which means `g` is not in the group.
+    pub fn element_order(&self, g: &T) -> usize {
+        let identity = g.op(&g.inverse());
+
+        let mut k = 1;
+        let mut current = g.clone();
+        while current != identity {
+            if k >= self.order() {
+                log::error!("Element order exceeds the group order {}, the element is not in the group", self.order());
+                panic!("Element order exceeds the group order, the element is not in the group");
+            }
+            current = current.op(g);
+            k += 1;
+        }
+        k
     }
 
+    /// Returns the Cayley table of the group, where entry `[i][j]` is the index (into `self.elements()`)
+    /// of `elements[i].op(&elements[j])`.
+    /// it will return error if a product is not found in the group, i.e. the group is not closed.
+    pub fn cayley_table(&self) -> Result<Vec<Vec<usize>>, AbsaglError> {
+        let index: HashMap<&T, usize> = self.elements.iter().enumerate().map(|(i, x)| (x, i)).collect();
+
+        let mut table = Vec::with_capacity(self.elements.len());
+        for a in &self.elements {
+            let mut row = Vec::with_capacity(self.elements.len());
+            for b in &self.elements {
+                match index.get(&self.operate(a, b)) {
+                    Some(&k) => row.push(k),
+                    None => {
+                        log::error!("The product is not in the group, cannot build Cayley table");
+                        return Err(GroupError::NotClosed)?;
+                    }
+                }
+            }
+            table.push(row);
+        }
+        Ok(table)
+    }
+
+    /// Returns the directed edges `(from, to, k)` of the Cayley graph with respect to `generators`,
+    /// where `to` is the index of `elements[from] * generators[k]`, indices refer into `self.elements()`.
+    /// generators that are not in the group are skipped, so their index `k` has no edges,
+    /// and if the generators don't generate the group the graph is simply disconnected.
+    pub fn cayley_graph(&self, generators: &[T]) -> Vec<(usize, usize, usize)> {
+        let index: HashMap<&T, usize> = self.elements.iter().enumerate().map(|(i, x)| (x, i)).collect();
+        let mut edges = Vec::new();
+        for (k, g) in generators.iter().enumerate() {
+            if !index.contains_key(g) {
+                log::warn!("Generator {} is not in the group, it is skipped", k);
+                continue;
+            }
+            for (from, x) in self.elements.iter().enumerate() {
+                if let Some(&to) = index.get(&x.op(g)) {
+                    edges.push((from, to, k));
+                }
+            }
+        }
+        edges
+    }
+
+    /// Returns the center of the group, the subgroup of elements `z` such that `z.op(x) == x.op(z)` for all `x`.
+    /// for abelian group, the center is the whole group.
+    /// this is a single-threaded implementation, if you want to use parallel computing, use `center_parallel()`
+    pub fn center(&self) -> FiniteGroup<T> {
+        let elements = self.elements
+            .iter()
+            .filter(|z| self.elements.iter().all(|x| z.op(x) == x.op(z)))
+            .cloned()
+            .collect();
+        FiniteGroup::new(elements)
+    }
+
+    /// Returns the center of the group in parallel, this is useful for large groups.
+    pub fn center_parallel(&self) -> FiniteGroup<T>
+    where
+        T: Send,
+    {
+        let elements = self.elements
+            .par_iter()
+            .filter(|z| self.elements.par_iter().all(|x| z.op(x) == x.op(z)))
+            .cloned()
+            .collect();
+        FiniteGroup::new(elements)
+    }
+
+    /// Returns the centralizer of `g`, the subgroup of all `x` such that `x.op(g) == g.op(x)`.
+    pub fn centralizer(&self, g: &T) -> FiniteGroup<T> {
+        let elements = self.elements
+            .iter()
+            .filter(|x| x.op(g) == g.op(x))
+            .cloned()
+            .collect();
+        FiniteGroup::new(elements)
+    }
+
+    /// Returns the inner automorphism `x ↦ g x g⁻¹` given by conjugation with `g`.
+    /// conjugation is always an automorphism, so no check is done. the inner automorphisms form Inn(G) ≅ G/Z(G),
+    /// two elements give the same map iff they lie in the same coset of the center.
+    pub fn inner_automorphism(&self, g: &T) -> Homomorphism<T, T, impl Fn(&T) -> T + use<T>> {
+        let g = g.clone();
+        let g_inv = g.inverse();
+        Homomorphism::new(move |x: &T| g.op(x).op(&g_inv), Some("inner automorphism x ↦ g x g⁻¹".to_string()))
+    }
+
+    /// Returns the normalizer of subgroup `h`, the subgroup of all `x` such that `x H x⁻¹ == H`.
+    /// the conjugated subgroup is compared with `H` by set equality.
+    pub fn normalizer(&self, h: &FiniteGroup<T>) -> FiniteGroup<T> {
+        let elements = self.elements
+            .iter()
+            .filter(|x| h.conjugate_by(x) == *h)
+            .cloned()
+            .collect();
+        FiniteGroup::new(elements)
+    }
+
+    /// Returns the conjugate subgroup `g H g⁻¹ = { g h g⁻¹ : h ∈ H }` of `self`, it has the same order as `self`.
+    /// `g` doesn't have to be in `self`, typically it comes from a larger group containing it.
+    pub fn conjugate_by(&self, g: &T) -> FiniteGroup<T> {
+        let g_inv = g.inverse();
+        FiniteGroup::new(self.elements.iter().map(|h| g.op(h).op(&g_inv)).collect())
+    }
+
+    /// Returns the derived (commutator) subgroup `[G,G]`, generated by all commutators `a⁻¹ b⁻¹ a b`.
+    /// the derived subgroup is always normal, so it can be passed to `FactorGroup::new` to build the abelianization `G/[G,G]`.
+    pub fn derived_subgroup(&self) -> FiniteGroup<T> {
+        let mut commutators = HashSet::new();
+        for a in &self.elements {
+            for b in &self.elements {
+                commutators.insert(a.inverse().op(&b.inverse()).op(a).op(b));
+            }
+        }
+        let commutators: Vec<T> = commutators.into_iter().collect();
+
+        FiniteGroup::new(Self::close_under_op(self.identity(), &commutators))
+    }
+
+    /// BFS closure of `generators` under `op`, starting from `identity`.
+    /// in a finite group, every inverse is a positive power, so the closure is the generated subgroup.
+    fn close_under_op(identity: T, generators: &[T]) -> Vec<T> {
+        let mut elements = HashSet::new();
+        let mut queue = Vec::new();
+
+        elements.insert(identity.clone());
+        queue.push(identity);
+
+        while let Some(current) = queue.pop() {
+            for g in generators {
+                let product = current.op(g);
+                if elements.insert(product.clone()) {
+                    queue.push(product);
+                }
+            }
+        }
+
+        elements.into_iter().collect()
+    }
+
+    /// Returns a generator of the group if it is cyclic, i.e. an element `g` with `element_order(g) == self.order()`.
+    pub fn find_generator(&self) -> Option<T> {
+        self.elements
+            .iter()
+            .find(|g| self.element_order(g) == self.order())
+            .cloned()
+    }
+
+    /// Checks if the group is cyclic, i.e. it can be generated by a single element.
+    pub fn is_cyclic(&self) -> bool {
+        self.find_generator().is_some()
+    }
+
+    /// Returns a one line summary of the group, e.g. `group of order 6, non-abelian, non-cyclic`.
+    pub fn describe(&self) -> String {
+        format!(
+            "group of order {}, {}, {}",
+            self.order(),
+            if self.is_abelian() { "abelian" } else { "non-abelian" },
+            if self.is_cyclic() { "cyclic" } else { "non-cyclic" },
+        )
+    }
+
+    /// Generates the subgroup spanned by `generators`, by BFS-closing the generators and their inverses under `op`.
+    /// this doesn't need the ambient group, e.g. it can build subgroups of `DihedralElement` or `Modulo` directly.
+    /// the identity is taken as `g * g⁻¹` for the first generator.
+    ///
+    /// `generate_from(&[])` has no element to take the identity from and returns `Err(GroupError::NotFound)`,
+    /// it never yields the trivial subgroup. To generate from a possibly empty list,
+    /// call `generate_from_with_identity`, which returns `{ identity }` for no generators.
+    pub fn generate_from(generators: &[T]) -> Result<FiniteGroup<T>, AbsaglError> {
+        let Some(first) = generators.first() else {
+            log::error!("Cannot determine the identity from an empty generator list");
+            return Err(GroupError::NotFound)?;
+        };
+        Ok(Self::generate_from_with_identity(first.op(&first.inverse()), generators))
+    }
+
+    /// Same as `generate_from`, with the identity given explicitly,
+    /// so an empty generator list yields the trivial subgroup `{ identity }`.
+    pub fn generate_from_with_identity(identity: T, generators: &[T]) -> FiniteGroup<T> {
+        let mut closure_generators = generators.to_vec();
+        closure_generators.extend(generators.iter().map(|g| g.inverse()));
+
+        FiniteGroup::new(Self::close_under_op(identity, &closure_generators))
+    }
+
+    /// Returns the normal closure of `generators`, the smallest normal subgroup containing them.
+    /// it is generated by all conjugates `g x g⁻¹` of the generators, a set that is closed under conjugation.
+    /// unlike `generate_normal_subgroup`, the whole group is a valid result, and no generators give the trivial subgroup.
+    pub fn normal_closure(&self, generators: &[T]) -> FiniteGroup<T> {
+        let conjugates: HashSet<T> = generators
+            .iter()
+            .flat_map(|x| self.elements.iter().map(move |g| g.op(x).op(&g.inverse())))
+            .collect();
+        let mut closure_generators: Vec<T> = conjugates.into_iter().collect();
+        closure_generators.extend(closure_generators.iter().map(|c| c.inverse()).collect::<Vec<T>>());
+        FiniteGroup::new(Self::close_under_op(self.identity(), &closure_generators))
+    }
+
+    /// Checks if the group is simple, i.e. it is not trivial and its only normal subgroups are `{e}` and itself.
+    /// instead of enumerating the normal subgroups, it uses that every nontrivial normal subgroup contains
+    /// the normal closure of one of its elements, so the group is simple iff each `g != e` has the whole group as normal closure.
+    pub fn is_simple(&self) -> bool {
+        if self.order() <= 1 {
+            return false;
+        }
+        let identity = self.identity();
+        self.elements
+            .iter()
+            .filter(|g| **g != identity)
+            .all(|g| self.normal_closure(std::slice::from_ref(g)).order() == self.order())
+    }
+
+    /// Checks if `candidate` is a subgroup of the group, i.e. every element of `candidate` is in the group
+    /// and `candidate` is closed. unlike `is_normal`, this doesn't assume the argument is already a subgroup.
+    pub fn is_subgroup(&self, candidate: &FiniteGroup<T>) -> bool {
+        if candidate.elements.is_empty() {
+            return false;
+        }
+        let group_set: HashSet<&T> = self.elements.iter().collect();
+        if candidate.elements.iter().any(|h| !group_set.contains(h)) {
+            return false;
+        }
+        candidate.is_closed()
+    }
+
+    /// Returns the exponent of the group, the lcm of all element orders.
+    /// for cyclic group it equals the group order, e.g. Klein four-group has exponent 2 but order 4.
+    pub fn exponent(&self) -> usize {
+        self.elements
+            .iter()
+            .map(|g| self.element_order(g))
+            .fold(1, utils::lcm)
+    }
+
+    /// Returns how many elements have each order, e.g. `{1: 1, 2: 1, 4: 2}` for Z_4 and `{1: 1, 2: 3}` for the Klein four-group.
+    /// isomorphic groups have the same profile, it is the same multiset of orders `is_isomorphic_to` compares first.
+    pub fn order_profile(&self) -> BTreeMap<usize, usize> {
+        let mut profile = BTreeMap::new();
+        for g in &self.elements {
+            *profile.entry(self.element_order(g)).or_insert(0) += 1;
+        }
+        profile
+    }
 
+    /// Returns `Some(p)` if the group order is a power `p^k` of a prime p, with k ≥ 1.
+    /// the trivial group is `p^0` for every p, so it returns `None`.
+    pub fn is_p_group(&self) -> Option<u64> {
+        match utils::prime_factorization(self.order() as u64).as_slice() {
+            [(p, _)] => Some(*p),
+            _ => None,
+        }
+    }
+
+    /// Checks if the group is isomorphic to `other`.
+    /// it first compares fast invariants: order, abelian-ness and the multiset of element orders,
+    /// then backtracks over the images of a small generating set, keeping only images of the same order,
+    /// and checks if the assignment extends to a structure-preserving bijection.
+    /// the search is exhaustive, so it's intended for small groups (e.g. order ≤ 16).
+    pub fn is_isomorphic_to<U: GroupElement>(&self, other: &FiniteGroup<U>) -> bool {
+        if self.order() != other.order() {
+            return false;
+        }
+        if self.elements.is_empty() {
+            return true;
+        }
+        if self.is_abelian() != other.is_abelian() {
+            return false;
+        }
+
+        let self_orders: Vec<usize> = self.elements.iter().map(|g| self.element_order(g)).collect();
+        let other_orders: Vec<usize> = other.elements.iter().map(|u| other.element_order(u)).collect();
+        let mut self_sorted = self_orders.clone();
+        let mut other_sorted = other_orders.clone();
+        self_sorted.sort_unstable();
+        other_sorted.sort_unstable();
+        if self_sorted != other_sorted {
+            return false;
+        }
+
+        let generators = self.generating_set(&self_orders);
+        let candidates: Vec<Vec<&U>> = generators
+            .iter()
+            .map(|g| {
+                let k = self.element_order(g);
+                other.elements.iter().zip(&other_orders).filter(|(_, o)| **o == k).map(|(u, _)| u).collect()
+            })
+            .collect();
+
+        let identity = self.identity();
+        let other_identity = other.identity();
+
+        fn backtrack<T: GroupElement, U: GroupElement>(
+            generators: &[T],
+            candidates: &[Vec<&U>],
+            images: &mut Vec<U>,
+            identity: &T,
+            other_identity: &U,
+            order: usize,
+        ) -> bool {
+            if images.len() == generators.len() {
+                return extends_to_isomorphism(generators, images, identity, other_identity, order);
+            }
+            for u in &candidates[images.len()] {
+                images.push((*u).clone());
+                if backtrack(generators, candidates, images, identity, other_identity, order) {
+                    return true;
+                }
+                images.pop();
+            }
+            false
+        }
+
+        /// the map defined by the generator images must be a well-defined homomorphism that is also injective
+        fn extends_to_isomorphism<T: GroupElement, U: GroupElement>(
+            generators: &[T],
+            images: &[U],
+            identity: &T,
+            other_identity: &U,
+            order: usize,
+        ) -> bool {
+            match extend_to_homomorphism(generators, images, identity, other_identity) {
+                Some(mapping) => {
+                    let image: HashSet<&U> = mapping.values().collect();
+                    mapping.len() == order && image.len() == order
+                }
+                None => false,
+            }
+        }
+
+        backtrack(&generators, &candidates, &mut Vec::new(), &identity, &other_identity, self.order())
+    }
+
+    /// Returns a small generating set of the group, greedily picking elements of largest order
+    /// that are not yet in the span of the chosen generators.
+    pub(crate) fn generating_set(&self, orders: &[usize]) -> Vec<T> {
+        let identity = self.identity();
+        let mut by_order: Vec<(&T, usize)> = self.elements.iter().zip(orders.iter().copied()).collect();
+        by_order.sort_by_key(|(_, k)| std::cmp::Reverse(*k));
+
+        let mut generators = Vec::new();
+        let mut span: HashSet<T> = HashSet::from([identity.clone()]);
+        for (g, _) in by_order {
+            if span.len() == self.order() {
+                break;
+            }
+            if !span.contains(g) {
+                generators.push(g.clone());
+                span = Self::close_under_op(identity.clone(), &generators).into_iter().collect();
+            }
+        }
+        generators
+    }
+
+
+}
+
+impl<T: GroupElement + 'static> FiniteGroup<T> {
+
+    /// Returns the automorphisms of the group, the bijective homomorphisms `G -> G`, only meant for small groups.
+    /// they are the endomorphisms found by `Homomorphism::enumerate` that are injective, e.g. |Aut(Z_n)| = φ(n).
+    pub fn automorphism_group(&self) -> Vec<BoxedHomomorphism<T, T>> {
+        Homomorphism::enumerate(self, self)
+            .into_iter()
+            .filter(|f| f.is_injective(self))
+            .collect()
+    }
+}
+
+impl<T: GroupElement + fmt::Display> FiniteGroup<T> {
+
+    /// Returns the Cayley table as a printable string, using each element's `Display`.
+    /// the first row and column list the elements in the order of `self.elements()`.
+    pub fn format_cayley_table(&self) -> Result<String, AbsaglError> {
+        let table = self.cayley_table()?;
+        let labels: Vec<String> = self.elements.iter().map(|x| x.to_string()).collect();
+        let width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0).max(1);
+
+        let mut out = String::new();
+        out.push_str(&format!("{:>width$} |", "*", width = width));
+        for label in &labels {
+            out.push_str(&format!(" {:>width$}", label, width = width));
+        }
+        out.push('\n');
+        out.push_str(&"-".repeat((width + 1) * (labels.len() + 1) + 1));
+        out.push('\n');
+
+        for (i, row) in table.iter().enumerate() {
+            out.push_str(&format!("{:>width$} |", labels[i], width = width));
+            for &k in row {
+                out.push_str(&format!(" {:>width$}", labels[k], width = width));
+            }
+            out.push('\n');
+        }
+        Ok(out)
+    }
+}
+
+impl<T: GroupElement + CanonicalRepr> FiniteGroup<T> {
+
+    /// Builds a `GroupLookup` of the canonical bytes of the elements in O(n),
+    /// its `contains` then checks membership in O(1).
+    pub fn lookup(&self) -> GroupLookup<T> {
+        GroupLookup {
+            canonical_set: self.elements.iter().map(|x| x.to_canonical_bytes()).collect(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Enumerates all subgroups of the group, sorted by order.
+    /// it starts from the cyclic subgroups `<g>`, then iteratively joins each subgroup with an element
+    /// outside of it (closing under `op`) until no new subgroup appears, subgroups are deduped by hashing.
+    ///
+    /// this is expensive, roughly O(s * n^2 * log n) where s is the number of subgroups and n is the group order,
+    /// so it returns error if the group order is larger than `MAX_ALL_SUBGROUPS_ORDER`.
+    pub fn all_subgroups(&self) -> Result<Vec<FiniteGroup<T>>, AbsaglError> {
+        if self.order() > MAX_ALL_SUBGROUPS_ORDER {
+            log::error!("Group order {} is too large to enumerate all subgroups, maximum is {}", self.order(), MAX_ALL_SUBGROUPS_ORDER);
+            return Err(GroupError::OrderTooLarge)?;
+        }
+        if self.elements.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let identity = self.identity();
+
+        // map each subgroup to a (small) generating set, so joins stay cheap
+        let mut subgroups: HashMap<FiniteGroup<T>, Vec<T>> = HashMap::new();
+        let mut queue: Vec<FiniteGroup<T>> = Vec::new();
+
+        for g in &self.elements {
+            let subgroup = self.cyclic_subgroup(g);
+            if !subgroups.contains_key(&subgroup) {
+                subgroups.insert(subgroup.clone(), vec![g.clone()]);
+                queue.push(subgroup);
+            }
+        }
+
+        while let Some(subgroup) = queue.pop() {
+            let member: HashSet<&T> = subgroup.elements.iter().collect();
+            for g in &self.elements {
+                if member.contains(g) {
+                    continue;
+                }
+                let mut generators = subgroups[&subgroup].clone();
+                generators.push(g.clone());
+
+                let joined = FiniteGroup::new(Self::close_under_op(identity.clone(), &generators));
+                if !subgroups.contains_key(&joined) {
+                    subgroups.insert(joined.clone(), generators);
+                    queue.push(joined);
+                }
+            }
+        }
+
+        let mut result: Vec<FiniteGroup<T>> = subgroups.into_keys().collect();
+        result.sort_by_key(|h| h.order());
+        Ok(result)
+    }
+
+    /// Returns the Sylow p-subgroups, the subgroups of order p^k where p^k is the largest power of p dividing |G|.
+    /// if p does not divide |G| this is just the trivial subgroup.
+    /// it filters `all_subgroups` by order, so it has the same order limit, and returns `GroupError::NotPrime` if p is not a prime.
+    pub fn sylow_subgroups(&self, p: u64) -> Result<Vec<FiniteGroup<T>>, AbsaglError> {
+        if utils::prime_factorization(p) != [(p, 1)] {
+            log::error!("{} is not a prime", p);
+            return Err(GroupError::NotPrime)?;
+        }
+        let mut sylow_order = 1;
+        while (self.order() as u64).is_multiple_of(sylow_order * p) {
+            sylow_order *= p;
+        }
+        Ok(self.all_subgroups()?.into_iter().filter(|h| h.order() as u64 == sylow_order).collect())
+    }
+
+    /// Returns the normal subgroups of the group, sorted by order, i.e. the subgroups `FactorGroup::new` accepts.
+    /// it filters `all_subgroups` with `is_normal`, so it has the same order limit.
+    pub fn normal_subgroups(&self) -> Result<Vec<FiniteGroup<T>>, AbsaglError> {
+        Ok(self.all_subgroups()?.into_iter().filter(|h| self.is_normal(h)).collect())
+    }
+
+    /// Returns the distinct left cosets `gH` of `subgroup` as element sets, deduped by canonical bytes.
+    /// by Lagrange's theorem, there are `self.order() / subgroup.order()` of them.
+    pub fn left_cosets(&self, subgroup: &FiniteGroup<T>) -> Vec<Vec<T>> {
+        self.cosets(subgroup, |g, h| g.op(h))
+    }
+
+    /// Returns the distinct right cosets `Hg` of `subgroup` as element sets, deduped by canonical bytes.
+    /// by Lagrange's theorem, there are `self.order() / subgroup.order()` of them.
+    pub fn right_cosets(&self, subgroup: &FiniteGroup<T>) -> Vec<Vec<T>> {
+        self.cosets(subgroup, |g, h| h.op(g))
+    }
+
+    /// partition the group into cosets, `multiply(g, h)` gives an element of the coset represented by `g`.
+    fn cosets(&self, subgroup: &FiniteGroup<T>, multiply: impl Fn(&T, &T) -> T) -> Vec<Vec<T>> {
+        let mut visited: HashSet<Vec<u8>> = HashSet::with_capacity(self.elements.len());
+        let mut cosets = Vec::new();
+
+        for g in &self.elements {
+            if visited.contains(&g.to_canonical_bytes()) {
+                continue;
+            }
+            let coset: Vec<T> = subgroup.elements.iter().map(|h| multiply(g, h)).collect();
+            for x in &coset {
+                visited.insert(x.to_canonical_bytes());
+            }
+            cosets.push(coset);
+        }
+        cosets
+    }
+
+    /// Returns the intersection of two subgroups, elements are matched by their canonical bytes.
+    /// if both groups are subgroups of a common group, the intersection is a subgroup too,
+    /// and it always contains the identity.
+    pub fn intersection(&self, other: &FiniteGroup<T>) -> FiniteGroup<T> {
+        let other_bytes: HashSet<Vec<u8>> = other.elements.iter().map(|x| x.to_canonical_bytes()).collect();
+
+        let elements = self.elements
+            .iter()
+            .filter(|x| other_bytes.contains(&x.to_canonical_bytes()))
+            .cloned()
+            .collect();
+        FiniteGroup::new(elements)
+    }
+}
+
+impl<'a, T: GroupElement> IntoIterator for &'a FiniteGroup<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    /// Allows `for g in &group`, yielding each element by reference.
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter()
+    }
 }
 
 impl<T: GroupElement> PartialEq for FiniteGroup<T> {
@@ -357,7 +1005,6 @@ impl<T: GroupElement> Eq for FiniteGroup<T> {}
 //         sorted_elements.hash(state);
 //     }
 // }
-
 impl<T: GroupElement + CanonicalRepr> Hash for FiniteGroup<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         // Create a list of the canonical byte representations.
@@ -376,9 +1023,22 @@ impl<T: GroupElement + CanonicalRepr> Hash for FiniteGroup<T> {
 }
 
 
+impl<T: GroupElement + fmt::Display> fmt::Display for FiniteGroup<T> {
+    /// Prints the elements as a set, e.g. `{ e, r^1, r^2 }`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.elements.is_empty() {
+            return write!(f, "{{}}");
+        }
+        let labels: Vec<String> = self.elements.iter().map(|x| x.to_string()).collect();
+        write!(f, "{{ {} }}", labels.join(", "))
+    }
+}
+
+
 /// Represents the decomposition of a finite abelian group
 /// into a direct product of cyclic groups of prime-power order.
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AbelianDecomposition {
     pub prime_power_orders: Vec<(u64, u32)>, // Vec of (prime, exponent)
 }
@@ -391,14 +1051,48 @@ impl AbelianDecomposition {
             .map(|(p, k)| p.pow(*k))
             .product()
     }
+
+    /// Converts the prime-power form into invariant factors `d_1 | d_2 | ... | d_k`, sorted ascending,
+    /// e.g. Z_4 × Z_2 × Z_3 gives `[2, 12]`, i.e. Z_2 × Z_12. the trivial group has no invariant factors.
+    /// the i-th largest factor multiplies the i-th largest power of every prime.
+    pub fn invariant_factors(&self) -> Vec<u64> {
+        let mut powers: BTreeMap<u64, Vec<u32>> = BTreeMap::new();
+        for &(p, k) in &self.prime_power_orders {
+            powers.entry(p).or_default().push(k);
+        }
+        let count = powers.values().map(|exponents| exponents.len()).max().unwrap_or(0);
+        let mut factors = vec![1; count];
+        for (p, exponents) in powers.iter_mut() {
+            exponents.sort_unstable_by(|a, b| b.cmp(a));
+            for (i, k) in exponents.iter().enumerate() {
+                factors[i] *= p.pow(*k);
+            }
+        }
+        factors.reverse();
+        factors
+    }
 }
 
+impl fmt::Display for AbelianDecomposition {
+    /// Prints the cyclic factors in the stored order, e.g. `Z_4 × Z_2 × Z_3`, the trivial group is `Z_1`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.prime_power_orders.is_empty() {
+            return write!(f, "Z_1");
+        }
+        let factors: Vec<String> = self.prime_power_orders.iter().map(|(p, k)| format!("Z_{}", p.pow(*k))).collect();
+        write!(f, "{}", factors.join(" × "))
+    }
+}
 
 
 
 
 
 
+
+/// The projection of a `DirectProductGroup` onto one of its cyclic factors, see `DirectProductGroup::projection`.
+pub type FactorProjection = BoxedHomomorphism<DirectProductElement, modulo::Modulo<Additive>>;
+
 /// Represents the direct product group structure itself.
 #[derive(Debug, Clone)]
 pub struct DirectProductGroup {
@@ -426,6 +1120,43 @@ impl DirectProductGroup {
             .collect();
         DirectProductElement { components }
     }
+
+    /// Enumerates all elements of the product as the Cartesian product of the factors' elements,
+    /// the first factor varies slowest. a product with no factors is the trivial group.
+    pub fn to_finite_group(&self) -> FiniteGroup<DirectProductElement> {
+        let mut elements = vec![DirectProductElement { components: vec![] }];
+        for factor in &self.factors {
+            elements = elements
+                .iter()
+                .flat_map(|x| {
+                    factor.iter().map(move |c| {
+                        let mut components = x.components.clone();
+                        components.push(*c);
+                        DirectProductElement { components }
+                    })
+                })
+                .collect();
+        }
+        FiniteGroup::new(elements)
+    }
+
+    /// The canonical projection onto the `i`-th factor, `(x_0, ..., x_n) ↦ x_i`.
+    /// returns `DirectProductError::ComponentOutOfRange` if `i` is not less than the number of factors.
+    /// the returned map panics when applied to an element with no `i`-th component, i.e. one not from this product.
+    pub fn projection(&self, i: usize) -> Result<FactorProjection, AbsaglError> {
+        if i >= self.factors.len() {
+            log::error!("Component {} is out of range for a product of {} factors", i, self.factors.len());
+            return Err(DirectProductError::ComponentOutOfRange { index: i, count: self.factors.len() })?;
+        }
+        Ok(Homomorphism::new(
+            Box::new(move |x: &DirectProductElement| {
+                *x.components
+                    .get(i)
+                    .unwrap_or_else(|| panic!("Cannot project onto factor {} of an element with {} components", i, x.components.len()))
+            }),
+            Some(format!("projection onto factor {}", i)),
+        ))
+    }
 }
 
 /// a collection of group generators
@@ -436,29 +1167,63 @@ impl GroupGenerators {
     /// Generates a modulo group with additive operation
     pub fn generate_modulo_group_add(n: usize) -> Result<FiniteGroup<modulo::Modulo<Additive>>, AbsaglError> {
         let elements = modulo::Modulo::<Additive>::generate_group(n as u64)?;
-        Ok(FiniteGroup::try_new(elements)?)
+        FiniteGroup::try_new(elements)
     }
     /// Generates a modulo group with Multiplicative operation
     pub fn generate_modulo_group_mul(n: usize) -> Result<FiniteGroup<modulo::Modulo<Multiplicative>>, AbsaglError> {
         let elements = modulo::Modulo::<Multiplicative>::generate_group(n as u64)?;
-        Ok(FiniteGroup::try_new(elements)?)
+        FiniteGroup::try_new(elements)
     }
     /// Generates permutation groups
     pub fn generate_permutation_group(n: usize) -> Result<FiniteGroup<permutation::Permutation>, AbsaglError> {
         let elements = permutation::Permutation::generate_group(n)?;
-        Ok(FiniteGroup::try_new(elements)?)
+        FiniteGroup::try_new(elements)
     }
     /// Generates alternating groups
     pub fn generate_alternating_group(n: usize) -> Result<FiniteGroup<permutation::Permutation>, AbsaglError> {
         let elements = permutation::Permutation::generate_alternative_group(n)?;
-        Ok(FiniteGroup::try_new(elements)?)
+        FiniteGroup::try_new(elements)
     }
     /// Generates dihedral groups
     pub fn generate_dihedral_group(n: usize) -> Result<FiniteGroup<dihedral::DihedralElement>, AbsaglError> {
         let elements = dihedral::DihedralElement::generate_group(n)?;
-        Ok(FiniteGroup::try_new(elements)?)
+        FiniteGroup::try_new(elements)
         
     }
+    /// Generates the Klein four-group V4 = {e, (0 1)(2 3), (0 2)(1 3), (0 3)(1 2)} as a subgroup of S4
+    pub fn generate_klein_four() -> Result<FiniteGroup<permutation::Permutation>, AbsaglError> {
+        let elements = vec![
+            permutation::Permutation::identity(4),
+            permutation::Permutation::from_cycles(&[vec![0, 1], vec![2, 3]], 4)?,
+            permutation::Permutation::from_cycles(&[vec![0, 2], vec![1, 3]], 4)?,
+            permutation::Permutation::from_cycles(&[vec![0, 3], vec![1, 2]], 4)?,
+        ];
+        FiniteGroup::try_new(elements)
+    }
+    /// Generates the quaternion group Q8
+    pub fn generate_quaternion() -> Result<FiniteGroup<quaternion::QuaternionElement>, AbsaglError> {
+        let elements = quaternion::QuaternionElement::generate_group();
+        FiniteGroup::try_new(elements)
+    }
+    /// Generates the dicyclic group Dic_n of order 4n, Dic_2 is the quaternion group Q8
+    pub fn generate_dicyclic(n: usize) -> Result<FiniteGroup<dicyclic::DicyclicElement>, AbsaglError> {
+        let elements = dicyclic::DicyclicElement::generate_group(n)?;
+        FiniteGroup::try_new(elements)
+    }
+    /// Generates the abelian group Z_d1 × Z_d2 × ... from its invariant factors, as the Cartesian product of the cyclic groups.
+    /// the factors are used as given, so they don't have to form a divisibility chain,
+    /// and an empty list gives the trivial group. returns `ModuloError::ZeroModulus` if a factor is zero.
+    pub fn abelian_from_invariant_factors(factors: &[u64]) -> Result<FiniteGroup<DirectProductElement>, AbsaglError> {
+        let mut cyclic_factors = Vec::new();
+        for &d in factors {
+            if d == 0 {
+                log::error!("Invariant factors cannot be zero");
+                return Err(modulo::ModuloError::ZeroModulus)?;
+            }
+            cyclic_factors.push(GroupGenerators::generate_modulo_group_add(d as usize)?);
+        }
+        Ok(DirectProductGroup { factors: cyclic_factors }.to_finite_group())
+    }
 }
 
 
@@ -508,7 +1273,7 @@ mod test_finite_group {
         let mut s6_missing = s6.clone();
         s6_missing.pop(); // Remove one element
         let s6_group_missing = FiniteGroup::new(s6_missing);
-        assert_eq!(s6_group_missing.is_closed_parallel(), false);
+        assert!(!s6_group_missing.is_closed_parallel());
     }
 
     #[test]
@@ -562,7 +1327,7 @@ mod test_finite_group {
     #[test]
     fn test_generate_normal_subgroup_fail_not_normal() {
         let group = GroupGenerators::generate_permutation_group(3).unwrap();
-        let g1 = Permutation::from_cycles(&vec![vec![0,1]], 3).unwrap();
+        let g1 = Permutation::from_cycles(&[vec![0,1]], 3).unwrap();
 
         let result = group.generate_normal_subgroup(vec![g1]);
 
@@ -664,6 +1429,660 @@ mod test_finite_group {
         assert_eq!(decomposition.order(), 6);
     }
 
+    #[test]
+    fn test_abelian_decomposition_non_cyclic() {
+        // cyclic groups still decompose as the prime factorization of their order
+        for n in 1..=36 {
+            let group = GroupGenerators::generate_modulo_group_add(n).unwrap();
+            assert_eq!(group.abelian_decomposition().unwrap().prime_power_orders, utils::prime_factorization(n as u64));
+        }
+
+        // non-cyclic groups of the same order get several factors for the same prime
+        let klein = GroupGenerators::generate_klein_four().unwrap();
+        assert_eq!(klein.abelian_decomposition().unwrap().prime_power_orders, vec![(2, 1), (2, 1)]);
+        let group = GroupGenerators::abelian_from_invariant_factors(&[2, 12]).unwrap();
+        assert_eq!(group.abelian_decomposition().unwrap().prime_power_orders, vec![(2, 2), (2, 1), (3, 1)]);
+        let group = GroupGenerators::abelian_from_invariant_factors(&[2, 2, 2]).unwrap();
+        assert_eq!(group.abelian_decomposition().unwrap().prime_power_orders, vec![(2, 1), (2, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn test_abelian_decomposition_display_and_invariant_factors() {
+        let decomposition = GroupGenerators::abelian_from_invariant_factors(&[2, 12]).unwrap().abelian_decomposition().unwrap();
+        assert_eq!(decomposition.to_string(), "Z_4 × Z_2 × Z_3");
+        assert_eq!(decomposition.invariant_factors(), vec![2, 12]);
+
+        let z6 = GroupGenerators::generate_modulo_group_add(6).unwrap().abelian_decomposition().unwrap();
+        assert_eq!(z6.to_string(), "Z_2 × Z_3");
+        assert_eq!(z6.invariant_factors(), vec![6]);
+
+        let decomposition = AbelianDecomposition { prime_power_orders: vec![(2, 1), (2, 1), (2, 3), (3, 2), (5, 1), (5, 1)] };
+        assert_eq!(decomposition.invariant_factors(), vec![2, 10, 360]);
+        assert_eq!(decomposition.invariant_factors().iter().product::<u64>(), decomposition.order());
+
+        let trivial = AbelianDecomposition { prime_power_orders: vec![] };
+        assert_eq!(trivial.to_string(), "Z_1");
+        assert!(trivial.invariant_factors().is_empty());
+    }
+
+    #[test]
+    fn test_abelian_decomposition_round_trip() {
+        // Z_2 × Z_4 × Z_3 is not cyclic, so it must not decompose as Z_8 × Z_3
+        let group = GroupGenerators::abelian_from_invariant_factors(&[2, 12]).unwrap();
+        let decomposition = group.abelian_decomposition().unwrap();
+
+        let rebuilt = DirectProductGroup::from_decomposition(&decomposition).unwrap().to_finite_group();
+        assert_eq!(rebuilt.order(), 24);
+        assert!(rebuilt.is_closed());
+        assert!(rebuilt.is_isomorphic_to(&group));
+        assert!(!rebuilt.is_isomorphic_to(&GroupGenerators::generate_modulo_group_add(24).unwrap()));
+
+        let klein = GroupGenerators::generate_klein_four().unwrap();
+        let decomposition = klein.abelian_decomposition().unwrap();
+        assert!(DirectProductGroup::from_decomposition(&decomposition).unwrap().to_finite_group().is_isomorphic_to(&klein));
+
+        let trivial = DirectProductGroup { factors: vec![] }.to_finite_group();
+        assert_eq!(trivial.order(), 1);
+    }
+
+    #[test]
+    fn test_cyclic_subgroup_modulo() {
+        let z12 = GroupGenerators::generate_modulo_group_add(12).unwrap();
+        let g4 = Modulo::<Additive>::try_new(4, 12).unwrap();
+
+        let subgroup = z12.cyclic_subgroup(&g4);
+
+        let g0 = Modulo::<Additive>::try_new(0, 12).unwrap();
+        let g8 = Modulo::<Additive>::try_new(8, 12).unwrap();
+        let expected = FiniteGroup::try_new(vec![g0, g4, g8]).expect("should create a FiniteGroup");
+        assert_eq!(subgroup, expected);
+        assert!(subgroup.is_closed());
+    }
+
+    #[test]
+    fn test_cyclic_subgroup_identity() {
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let e = Permutation::identity(3);
+
+        let subgroup = s3.cyclic_subgroup(&e);
+        assert_eq!(subgroup.order(), 1);
+        assert!(subgroup.is_closed());
+    }
+
+    #[test]
+    fn test_cyclic_subgroup_permutation() {
+        let s4 = GroupGenerators::generate_permutation_group(4).unwrap();
+        let g = Permutation::from_cycles(&[vec![0, 1, 2, 3]], 4).unwrap();
+
+        let subgroup = s4.cyclic_subgroup(&g);
+        assert_eq!(subgroup.order(), 4);
+        assert!(subgroup.is_closed());
+    }
+
+    #[test]
+    fn test_element_order() {
+        let z12 = GroupGenerators::generate_modulo_group_add(12).unwrap();
+        let g0 = Modulo::<Additive>::try_new(0, 12).unwrap();
+        let g8 = Modulo::<Additive>::try_new(8, 12).unwrap();
+        assert_eq!(z12.element_order(&g0), 1);
+        assert_eq!(z12.element_order(&g8), 3);
+
+        let s4 = GroupGenerators::generate_permutation_group(4).unwrap();
+        let g = Permutation::from_cycles(&[vec![0, 1], vec![2, 3]], 4).unwrap();
+        assert_eq!(s4.element_order(&g), g.order());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_element_order_not_in_group() {
+        let z3 = GroupGenerators::generate_modulo_group_add(3).unwrap();
+        let g = Modulo::<Additive>::try_new(1, 5).unwrap();
+        z3.element_order(&g);
+    }
+
+    #[test]
+    fn test_cayley_table() {
+        let z3 = GroupGenerators::generate_modulo_group_add(3).unwrap();
+        let table = z3.cayley_table().expect("should build Cayley table");
+        assert_eq!(table, vec![vec![0, 1, 2], vec![1, 2, 0], vec![2, 0, 1]]);
+
+        // every row of a Cayley table is a permutation of the group
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let table = s3.cayley_table().expect("should build Cayley table");
+        for row in &table {
+            assert!(utils::is_mapping_valid(row));
+        }
+    }
+
+    #[test]
+    fn test_cayley_table_not_closed() {
+        let a = Modulo::<Additive>::try_new(0, 3).unwrap();
+        let b = Modulo::<Additive>::try_new(1, 3).unwrap();
+        let group = FiniteGroup::new(vec![a, b]);
+
+        let result = group.cayley_table();
+        match result {
+            Err(AbsaglError::Group(GroupError::NotClosed)) => {
+                // pass
+            }
+            _ => panic!("Expect Err(AbsaglError::Group(GroupError::NotClosed)), but got {:?}", result)
+        }
+    }
+
+    #[test]
+    fn test_format_cayley_table() {
+        let z2 = GroupGenerators::generate_modulo_group_add(2).unwrap();
+        let table = z2.format_cayley_table().expect("should format Cayley table");
+        assert_eq!(table.lines().count(), 4);
+        assert!(table.lines().nth(3).unwrap().ends_with("1 (mod 2)+ 0 (mod 2)+"));
+    }
+
+    #[test]
+    fn test_finite_group_iter() {
+        let z6 = GroupGenerators::generate_modulo_group_add(6).unwrap();
+        assert_eq!(z6.iter().count(), 6);
+        assert!(z6.iter().eq(z6.elements().iter()));
+
+        let mut count = 0;
+        for g in &z6 {
+            assert_eq!(z6.element_order(g), g.order() as usize);
+            count += 1;
+        }
+        assert_eq!(count, 6);
+        assert_eq!((&z6).into_iter().filter(|g| z6.element_order(g) == 6).count(), 2);
+    }
+
+    #[test]
+    fn test_finite_group_contains() {
+        let a4 = GroupGenerators::generate_alternating_group(4).unwrap();
+        let even = permutation::Permutation::from_cycles(&[vec![0, 1, 2]], 4).unwrap();
+        let odd = permutation::Permutation::from_cycles(&[vec![0, 1]], 4).unwrap();
+        assert!(a4.contains(&even));
+        assert!(!a4.contains(&odd));
+        let lookup = a4.lookup();
+        assert!(lookup.contains(&even));
+        assert!(!lookup.contains(&odd));
+    }
+
+    #[test]
+    fn test_finite_group_display_and_describe() {
+        let d3 = GroupGenerators::generate_dihedral_group(3).unwrap();
+        assert_eq!(format!("{}", d3), "{ e, r^1, r^2, s, sr^2, sr^1 }");
+        assert_eq!(d3.describe(), "group of order 6, non-abelian, non-cyclic");
+        let z4 = GroupGenerators::generate_modulo_group_add(4).unwrap();
+        assert_eq!(z4.describe(), "group of order 4, abelian, cyclic");
+        assert_eq!(format!("{}", FiniteGroup::<dihedral::DihedralElement>::new(vec![])), "{}");
+    }
+
+
+    #[test]
+    fn test_center_abelian() {
+        let z6 = GroupGenerators::generate_modulo_group_add(6).unwrap();
+        let center = z6.center();
+        assert_eq!(center, z6);
+    }
+
+    #[test]
+    fn test_center_permutation() {
+        // Z(S3) is trivial
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let center = s3.center();
+        assert_eq!(center.order(), 1);
+        assert!(center.is_closed());
+
+        // Z(S4) is trivial too, the parallel version should agree
+        let s4 = GroupGenerators::generate_permutation_group(4).unwrap();
+        assert_eq!(s4.center_parallel(), s4.center());
+        assert_eq!(s4.center_parallel().order(), 1);
+    }
+
+    #[test]
+    fn test_centralizer() {
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+
+        // the centralizer of a transposition in S3 is the subgroup it generates
+        let t = Permutation::from_cycles(&[vec![0, 1]], 3).unwrap();
+        let centralizer = s3.centralizer(&t);
+        assert_eq!(centralizer, s3.cyclic_subgroup(&t));
+        assert!(centralizer.is_closed());
+
+        // the centralizer of the identity is the whole group
+        let e = Permutation::identity(3);
+        assert_eq!(s3.centralizer(&e), s3);
+    }
+
+    #[test]
+    fn test_normalizer() {
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+
+        // A3 is normal in S3, so its normalizer is the whole group
+        let a3 = GroupGenerators::generate_alternating_group(3).unwrap();
+        assert_eq!(s3.normalizer(&a3), s3);
+
+        // <(0 1)> is not normal in S3, it is self-normalizing
+        let t = Permutation::from_cycles(&[vec![0, 1]], 3).unwrap();
+        let h = s3.cyclic_subgroup(&t);
+        let normalizer = s3.normalizer(&h);
+        assert_eq!(normalizer, h);
+        assert!(normalizer.is_closed());
+    }
+
+    #[test]
+    fn test_derived_subgroup() {
+        // [S3, S3] = A3
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let a3 = GroupGenerators::generate_alternating_group(3).unwrap();
+        let derived = s3.derived_subgroup();
+        assert_eq!(derived, a3);
+        assert!(s3.is_normal(&derived));
+
+        // [S4, S4] = A4
+        let s4 = GroupGenerators::generate_permutation_group(4).unwrap();
+        let a4 = GroupGenerators::generate_alternating_group(4).unwrap();
+        assert_eq!(s4.derived_subgroup(), a4);
+
+        // abelian group has trivial derived subgroup
+        let z6 = GroupGenerators::generate_modulo_group_add(6).unwrap();
+        assert_eq!(z6.derived_subgroup().order(), 1);
+    }
+
+    #[test]
+    fn test_is_cyclic() {
+        let z12 = GroupGenerators::generate_modulo_group_add(12).unwrap();
+        assert!(z12.is_cyclic());
+        let g = z12.find_generator().expect("Z12 should have a generator");
+        assert_eq!(z12.cyclic_subgroup(&g), z12);
+
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        assert!(!s3.is_cyclic());
+        assert_eq!(s3.find_generator(), None);
+
+        // (Z/8Z)* is not cyclic
+        let u8 = GroupGenerators::generate_modulo_group_mul(8).unwrap();
+        assert!(!u8.is_cyclic());
+    }
+
+    #[test]
+    fn test_all_subgroups_s3() {
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let subgroups = s3.all_subgroups().expect("should enumerate subgroups");
+
+        let orders: Vec<usize> = subgroups.iter().map(|h| h.order()).collect();
+        assert_eq!(orders, vec![1, 2, 2, 2, 3, 6]);
+        assert!(subgroups.iter().all(|h| h.is_closed()));
+    }
+
+    #[test]
+    fn test_all_subgroups_s4() {
+        // S4 has 30 subgroups
+        let s4 = GroupGenerators::generate_permutation_group(4).unwrap();
+        let subgroups = s4.all_subgroups().expect("should enumerate subgroups");
+        assert_eq!(subgroups.len(), 30);
+    }
+
+    #[test]
+    fn test_cayley_graph() {
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let t = Permutation::from_cycles(&[vec![0, 1]], 3).unwrap();
+        let c = Permutation::from_cycles(&[vec![0, 1, 2]], 3).unwrap();
+        let edges = s3.cayley_graph(&[t.clone(), c.clone()]);
+        assert_eq!(edges.len(), 12);
+        for &(from, to, k) in &edges {
+            let g = if k == 0 { &t } else { &c };
+            assert_eq!(s3.elements()[from].op(g), s3.elements()[to]);
+        }
+
+        // a single 3-cycle only reaches A_3 from the identity, the graph has two components
+        let edges = s3.cayley_graph(std::slice::from_ref(&c));
+        let identity = s3.elements().iter().position(|x| *x == s3.identity()).unwrap();
+        let mut reached = HashSet::from([identity]);
+        let mut frontier = vec![identity];
+        while let Some(x) = frontier.pop() {
+            for &(from, to, _) in &edges {
+                if from == x && reached.insert(to) {
+                    frontier.push(to);
+                }
+            }
+        }
+        assert_eq!(reached.len(), 3);
+
+        // a generator from outside the group has no edges
+        let outside = Permutation::from_cycles(&[vec![0, 1]], 4).unwrap();
+        let edges = s3.cayley_graph(&[outside, c]);
+        assert_eq!(edges.len(), 6);
+        assert!(edges.iter().all(|&(_, _, k)| k == 1));
+    }
+
+    #[test]
+    fn test_conjugate_by() {
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let a3 = GroupGenerators::generate_alternating_group(3).unwrap();
+        let t01 = Permutation::from_cycles(&[vec![0, 1]], 3).unwrap();
+        let t12 = Permutation::from_cycles(&[vec![1, 2]], 3).unwrap();
+        let t02 = Permutation::from_cycles(&[vec![0, 2]], 3).unwrap();
+
+        // a normal subgroup is its own conjugate
+        for g in s3.elements() {
+            assert_eq!(a3.conjugate_by(g), a3);
+        }
+
+        // (1 2) <(0 1)> (1 2) = <(0 2)>
+        let h = s3.cyclic_subgroup(&t01);
+        let conjugate = h.conjugate_by(&t12);
+        assert_eq!(conjugate.order(), h.order());
+        assert_eq!(conjugate, s3.cyclic_subgroup(&t02));
+        assert_ne!(conjugate, h);
+    }
+
+    #[test]
+    fn test_normal_subgroups() {
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let normal = s3.normal_subgroups().unwrap();
+        assert_eq!(normal.len(), 3);
+        assert_eq!(normal[0].order(), 1);
+        assert_eq!(normal[1], GroupGenerators::generate_alternating_group(3).unwrap());
+        assert_eq!(normal[2], s3);
+
+        // 1, V4, A4 and S4
+        let s4 = GroupGenerators::generate_permutation_group(4).unwrap();
+        let orders: Vec<usize> = s4.normal_subgroups().unwrap().iter().map(|h| h.order()).collect();
+        assert_eq!(orders, vec![1, 4, 12, 24]);
+
+        // every subgroup of an abelian group is normal
+        let z12 = GroupGenerators::generate_modulo_group_add(12).unwrap();
+        assert_eq!(z12.normal_subgroups().unwrap().len(), z12.all_subgroups().unwrap().len());
+    }
+
+    #[test]
+    fn test_sylow_subgroups() {
+        // S_3 has three Sylow 2-subgroups, one for each transposition, and a unique (normal) Sylow 3-subgroup A_3
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let sylow_2 = s3.sylow_subgroups(2).unwrap();
+        assert_eq!(sylow_2.len(), 3);
+        assert!(sylow_2.iter().all(|h| h.order() == 2));
+        let sylow_3 = s3.sylow_subgroups(3).unwrap();
+        assert_eq!(sylow_3, vec![GroupGenerators::generate_alternating_group(3).unwrap()]);
+
+        // S_4 has three Sylow 2-subgroups (copies of D_4) and four Sylow 3-subgroups, both ≡ 1 mod p and dividing |G|
+        let s4 = GroupGenerators::generate_permutation_group(4).unwrap();
+        let sylow_2 = s4.sylow_subgroups(2).unwrap();
+        assert_eq!(sylow_2.len(), 3);
+        assert!(sylow_2.iter().all(|h| h.order() == 8 && h.is_p_group() == Some(2)));
+        assert_eq!(s4.sylow_subgroups(3).unwrap().len(), 4);
+
+        assert_eq!(s3.sylow_subgroups(5).unwrap().iter().map(|h| h.order()).collect::<Vec<_>>(), vec![1]);
+        assert!(matches!(s3.sylow_subgroups(4), Err(AbsaglError::Group(GroupError::NotPrime))));
+    }
+
+    #[test]
+    fn test_all_subgroups_too_large() {
+        let z200 = GroupGenerators::generate_modulo_group_add(200).unwrap();
+        let result = z200.all_subgroups();
+        match result {
+            Err(AbsaglError::Group(GroupError::OrderTooLarge)) => {
+                // pass
+            }
+            _ => panic!("Expect Err(AbsaglError::Group(GroupError::OrderTooLarge)), but got {:?}", result)
+        }
+    }
+
+    #[test]
+    fn test_generate_from() {
+        let g4 = Modulo::<Additive>::try_new(4, 12).unwrap();
+        let g6 = Modulo::<Additive>::try_new(6, 12).unwrap();
+        let subgroup = FiniteGroup::generate_from(&[g4, g6]).expect("should generate subgroup");
+        // gcd(4, 6, 12) = 2, so <4, 6> = <2>
+        assert_eq!(subgroup.order(), 6);
+        assert!(subgroup.is_closed());
+
+        let r = dihedral::DihedralElement::try_new(1, false, 5).unwrap();
+        let subgroup = FiniteGroup::generate_from(&[r]).expect("should generate subgroup");
+        assert_eq!(subgroup.order(), 5);
+
+        let t = Permutation::from_cycles(&[vec![0, 1]], 4).unwrap();
+        let c = Permutation::from_cycles(&[vec![0, 1, 2, 3]], 4).unwrap();
+        let s4 = FiniteGroup::generate_from(&[t, c]).expect("should generate subgroup");
+        assert_eq!(s4, GroupGenerators::generate_permutation_group(4).unwrap());
+    }
+
+    #[test]
+    fn test_generate_from_empty() {
+        let result = FiniteGroup::<Modulo<Additive>>::generate_from(&[]);
+        match result {
+            Err(AbsaglError::Group(GroupError::NotFound)) => {
+                // pass
+            }
+            _ => panic!("Expect Err(AbsaglError::Group(GroupError::NotFound)), but got {:?}", result)
+        }
+        assert!(FiniteGroup::<Permutation>::generate_from(&[]).is_err());
+
+        // with the identity given, no generators give the trivial subgroup
+        let e = Modulo::<Additive>::try_new(0, 12).unwrap();
+        let trivial = FiniteGroup::generate_from_with_identity(e, &[]);
+        assert_eq!(trivial.elements(), &[e]);
+        assert!(trivial.is_closed());
+
+        let g4 = Modulo::<Additive>::try_new(4, 12).unwrap();
+        assert_eq!(FiniteGroup::generate_from_with_identity(e, &[g4]), FiniteGroup::generate_from(&[g4]).unwrap());
+    }
+
+    #[test]
+    fn test_is_subgroup() {
+        let z12 = GroupGenerators::generate_modulo_group_add(12).unwrap();
+        let g0 = Modulo::<Additive>::try_new(0, 12).unwrap();
+        let g3 = Modulo::<Additive>::try_new(3, 12).unwrap();
+
+        assert!(z12.is_subgroup(&z12));
+        assert!(z12.is_subgroup(&FiniteGroup::new(vec![g0])));
+        assert!(z12.is_subgroup(&z12.cyclic_subgroup(&g3)));
+
+        // not closed
+        assert!(!z12.is_subgroup(&FiniteGroup::new(vec![g0, g3])));
+
+        // elements from another group
+        let z6 = GroupGenerators::generate_modulo_group_add(6).unwrap();
+        assert!(!z12.is_subgroup(&z6));
+    }
+
+    #[test]
+    fn test_normal_closure() {
+        let s4 = GroupGenerators::generate_permutation_group(4).unwrap();
+        let transposition = Permutation::from_cycles(&[vec![0, 1]], 4).unwrap();
+        let three_cycle = Permutation::from_cycles(&[vec![0, 1, 2]], 4).unwrap();
+        let double = Permutation::from_cycles(&[vec![0, 1], vec![2, 3]], 4).unwrap();
+
+        // the conjugates of a transposition are all transpositions, which generate S_4
+        assert_eq!(s4.normal_closure(std::slice::from_ref(&transposition)), s4);
+        assert!(s4.generate_normal_subgroup(vec![transposition]).is_err());
+        assert_eq!(s4.normal_closure(&[three_cycle]).order(), 12);
+
+        let v4 = s4.normal_closure(&[double]);
+        assert_eq!(v4, GroupGenerators::generate_klein_four().unwrap());
+        assert!(s4.is_normal(&v4));
+        assert_eq!(s4.normal_closure(&[]).order(), 1);
+    }
+
+    #[test]
+    fn test_is_simple() {
+        assert!(GroupGenerators::generate_alternating_group(5).unwrap().is_simple());
+        // V4 is normal in A_4
+        let a4 = GroupGenerators::generate_alternating_group(4).unwrap();
+        assert!(!a4.is_simple());
+        assert_eq!(a4.normal_subgroups().unwrap().len(), 3);
+        assert!(GroupGenerators::generate_modulo_group_add(7).unwrap().is_simple());
+        assert!(!GroupGenerators::generate_modulo_group_add(6).unwrap().is_simple());
+        assert!(!GroupGenerators::generate_permutation_group(3).unwrap().is_simple());
+        assert!(!GroupGenerators::generate_modulo_group_add(1).unwrap().is_simple());
+    }
+
+    #[test]
+    fn test_is_normal_subgroup() {
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let a3 = GroupGenerators::generate_alternating_group(3).unwrap();
+        assert!(s3.is_normal_subgroup(&a3).unwrap());
+
+        let transposition = Permutation::from_cycles(&[vec![0, 1]], 3).unwrap();
+        assert!(!s3.is_normal_subgroup(&s3.cyclic_subgroup(&transposition)).unwrap());
+
+        // {e, (0 1), (0 2)} is not closed, so it is an error rather than a misleading `false`
+        let not_closed = FiniteGroup::new(vec![
+            Permutation::identity(3),
+            transposition,
+            Permutation::from_cycles(&[vec![0, 2]], 3).unwrap(),
+        ]);
+        assert!(matches!(s3.is_normal_subgroup(&not_closed), Err(AbsaglError::Group(GroupError::NotContainedOrNotClosed))));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let z12 = GroupGenerators::generate_modulo_group_add(12).unwrap();
+        let g2 = Modulo::<Additive>::try_new(2, 12).unwrap();
+        let g3 = Modulo::<Additive>::try_new(3, 12).unwrap();
+
+        // <2> ∩ <3> = <6>
+        let h2 = z12.cyclic_subgroup(&g2);
+        let h3 = z12.cyclic_subgroup(&g3);
+        let intersection = h2.intersection(&h3);
+
+        let g6 = Modulo::<Additive>::try_new(6, 12).unwrap();
+        assert_eq!(intersection, z12.cyclic_subgroup(&g6));
+        assert!(intersection.is_closed());
+
+        // A3 ∩ <(0 1)> is trivial
+        let a3 = GroupGenerators::generate_alternating_group(3).unwrap();
+        let t = Permutation::from_cycles(&[vec![0, 1]], 3).unwrap();
+        let intersection = a3.intersection(&a3.cyclic_subgroup(&t));
+        assert_eq!(intersection.order(), 1);
+    }
+
+    #[test]
+    fn test_automorphism_group() {
+        for n in 1..=12 {
+            let zn = GroupGenerators::generate_modulo_group_add(n).unwrap();
+            assert_eq!(zn.automorphism_group().len() as u64, utils::euler_totient(n as u64));
+        }
+        // Aut(V4) = S_3 and Aut(S_3) = S_3
+        assert_eq!(GroupGenerators::generate_klein_four().unwrap().automorphism_group().len(), 6);
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let automorphisms = s3.automorphism_group();
+        assert_eq!(automorphisms.len(), 6);
+        assert!(automorphisms.iter().all(|f| f.is_automorphism(&s3).unwrap()));
+    }
+
+    #[test]
+    fn test_inner_automorphism() {
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let c = Permutation::from_cycles(&[vec![0, 1, 2]], 3).unwrap();
+        let t = Permutation::from_cycles(&[vec![0, 1]], 3).unwrap();
+        let f = s3.inner_automorphism(&t);
+        assert_eq!(f.apply(&c), c.inverse());
+        assert!(f.is_automorphism(&s3).unwrap());
+
+        // |Inn(G)| = |G| / |Z(G)|
+        fn count_inner<T: GroupElement>(group: &FiniteGroup<T>) -> usize {
+            let maps: HashSet<Vec<T>> = group
+                .elements()
+                .iter()
+                .map(|g| {
+                    let f = group.inner_automorphism(g);
+                    group.elements().iter().map(|x| f.apply(x)).collect()
+                })
+                .collect();
+            maps.len()
+        }
+        assert_eq!(count_inner(&s3), 6);
+        let d4 = GroupGenerators::generate_dihedral_group(4).unwrap();
+        assert_eq!(count_inner(&d4), d4.order() / d4.center().order());
+        let q8 = GroupGenerators::generate_quaternion().unwrap();
+        assert_eq!(count_inner(&q8), 4);
+        assert_eq!(count_inner(&GroupGenerators::generate_modulo_group_add(5).unwrap()), 1);
+    }
+
+    #[test]
+    fn test_order_profile() {
+        let z4 = GroupGenerators::generate_modulo_group_add(4).unwrap();
+        assert_eq!(z4.order_profile(), BTreeMap::from([(1, 1), (2, 1), (4, 2)]));
+        let v4 = GroupGenerators::generate_klein_four().unwrap();
+        assert_eq!(v4.order_profile(), BTreeMap::from([(1, 1), (2, 3)]));
+
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        assert_eq!(s3.order_profile(), GroupGenerators::generate_dihedral_group(3).unwrap().order_profile());
+        assert_eq!(s3.order_profile().values().sum::<usize>(), 6);
+    }
+
+    #[test]
+    fn test_is_p_group() {
+        assert_eq!(GroupGenerators::generate_quaternion().unwrap().is_p_group(), Some(2));
+        assert_eq!(GroupGenerators::generate_dihedral_group(4).unwrap().is_p_group(), Some(2));
+        assert_eq!(GroupGenerators::generate_modulo_group_add(9).unwrap().is_p_group(), Some(3));
+        assert_eq!(GroupGenerators::generate_permutation_group(3).unwrap().is_p_group(), None);
+        assert_eq!(GroupGenerators::generate_modulo_group_add(1).unwrap().is_p_group(), None);
+    }
+
+    #[test]
+    fn test_exponent() {
+        let z12 = GroupGenerators::generate_modulo_group_add(12).unwrap();
+        assert_eq!(z12.exponent(), 12);
+
+        // (Z/8Z)* is the Klein four-group
+        let u8 = GroupGenerators::generate_modulo_group_mul(8).unwrap();
+        assert_eq!(u8.order(), 4);
+        assert_eq!(u8.exponent(), 2);
+
+        let s4 = GroupGenerators::generate_permutation_group(4).unwrap();
+        assert_eq!(s4.exponent(), 12);
+    }
+
+    #[test]
+    fn test_left_right_cosets() {
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let t = Permutation::from_cycles(&[vec![0, 1]], 3).unwrap();
+        let h = s3.cyclic_subgroup(&t);
+
+        let left = s3.left_cosets(&h);
+        let right = s3.right_cosets(&h);
+        assert_eq!(left.len(), s3.order() / h.order());
+        assert_eq!(right.len(), s3.order() / h.order());
+
+        // <(0 1)> is not normal, so some left coset is not a right coset
+        let as_sets = |cosets: &Vec<Vec<Permutation>>| -> Vec<HashSet<Permutation>> {
+            cosets.iter().map(|c| c.iter().cloned().collect()).collect()
+        };
+        let left_sets = as_sets(&left);
+        let right_sets = as_sets(&right);
+        assert!(left_sets.iter().any(|c| !right_sets.contains(c)));
+
+        // for normal subgroup, left and right cosets coincide
+        let a3 = GroupGenerators::generate_alternating_group(3).unwrap();
+        let left_sets = as_sets(&s3.left_cosets(&a3));
+        let right_sets = as_sets(&s3.right_cosets(&a3));
+        assert!(left_sets.iter().all(|c| right_sets.contains(c)));
+    }
+
+    #[test]
+    fn test_is_isomorphic_to() {
+        // (Z/8Z)* is Z2 × Z2, which is not isomorphic to Z4
+        let u8 = GroupGenerators::generate_modulo_group_mul(8).unwrap();
+        let z4 = GroupGenerators::generate_modulo_group_add(4).unwrap();
+        assert!(!u8.is_isomorphic_to(&z4));
+
+        // (Z/8Z)* and (Z/12Z)* are both Z2 × Z2
+        let u12 = GroupGenerators::generate_modulo_group_mul(12).unwrap();
+        assert!(u8.is_isomorphic_to(&u12));
+
+        // (Z/7Z)* is cyclic of order 6
+        let u7 = GroupGenerators::generate_modulo_group_mul(7).unwrap();
+        let z6 = GroupGenerators::generate_modulo_group_add(6).unwrap();
+        assert!(u7.is_isomorphic_to(&z6));
+
+        // S3 acting on {2, 3, 4} inside S5 is isomorphic to S3, but not to Z6
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let t = Permutation::from_cycles(&[vec![2, 3]], 5).unwrap();
+        let c = Permutation::from_cycles(&[vec![2, 3, 4]], 5).unwrap();
+        let s3_in_s5 = FiniteGroup::generate_from(&[t, c]).unwrap();
+        assert!(s3.is_isomorphic_to(&s3_in_s5));
+        assert!(!s3.is_isomorphic_to(&z6));
+    }
 
     // #[test]
     // #[should_panic] // This test is expected to fail to compile, not panic at runtime
@@ -680,6 +2099,36 @@ mod test_finite_group {
     //     set.insert(group);
     // }
 
+    #[test]
+    fn test_direct_product_group_projection() {
+        // Z_2 × Z_4
+        let product = DirectProductGroup::from_decomposition(&AbelianDecomposition { prime_power_orders: vec![(2, 1), (2, 2)] }).unwrap();
+        let elements = product.factors[0]
+            .elements()
+            .iter()
+            .flat_map(|a| product.factors[1].elements().iter().map(move |b| DirectProductElement { components: vec![*a, *b] }))
+            .collect();
+        let group = FiniteGroup::new(elements);
+
+        // first isomorphism theorem: |G| / |ker| = |im|
+        let projection = product.projection(1).unwrap();
+        let kernel = projection.kernel(&group, &Modulo::<Additive>::identity(4)).unwrap();
+        let image = projection.image(&group).unwrap();
+        assert_eq!(kernel.order(), 2);
+        assert_eq!(group.order() / kernel.order(), image.order());
+        assert!(projection.is_surjective(&group, &product.factors[1]).unwrap());
+
+        assert!(product.projection(2).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot project onto factor 1 of an element with 1 components")]
+    fn test_direct_product_group_projection_short_element() {
+        let product = DirectProductGroup::from_decomposition(&AbelianDecomposition { prime_power_orders: vec![(2, 1), (3, 1)] }).unwrap();
+        let projection = product.projection(1).unwrap();
+        projection.apply(&DirectProductElement { components: vec![Modulo::<Additive>::identity(2)] });
+    }
+
 }
 
 
@@ -719,4 +2168,54 @@ mod test_group_generators {
         let group = GroupGenerators::generate_dihedral_group(3).expect("Failed to generate dihedral group");
         assert_eq!(group.order(), 6); // D3 has 6 elements
     }
+
+    #[test]
+    fn test_generate_klein_four() {
+        let group = GroupGenerators::generate_klein_four().expect("Failed to generate Klein four-group");
+        assert_eq!(group.order(), 4);
+        assert!(group.is_abelian());
+        assert_eq!(group.exponent(), 2);
+    }
+
+    #[test]
+    fn test_generate_quaternion() {
+        let group = GroupGenerators::generate_quaternion().expect("Failed to generate quaternion group");
+        assert_eq!(group.order(), 8);
+        assert!(!group.is_abelian());
+        // the center of Q8 is {1, -1}
+        assert_eq!(group.center().order(), 2);
+    }
+
+    #[test]
+    fn test_generate_dicyclic() {
+        let group = GroupGenerators::generate_dicyclic(2).expect("Failed to generate dicyclic group");
+        assert_eq!(group.order(), 8);
+        assert!(group.is_isomorphic_to(&GroupGenerators::generate_quaternion().unwrap()));
+        assert!(!group.is_isomorphic_to(&GroupGenerators::generate_dihedral_group(4).unwrap()));
+
+        let group = GroupGenerators::generate_dicyclic(3).unwrap();
+        assert_eq!(group.order(), 12);
+        assert!(!group.is_abelian());
+        // Dic_n has a unique element of order 2, a^n
+        assert_eq!(group.elements.iter().filter(|g| g.order() == 2).count(), 1);
+        assert!(GroupGenerators::generate_dicyclic(0).is_err());
+    }
+
+    #[test]
+    fn test_abelian_from_invariant_factors() {
+        let group = GroupGenerators::abelian_from_invariant_factors(&[2, 6]).unwrap();
+        assert_eq!(group.order(), 12);
+        assert!(group.is_abelian());
+        assert!(!group.is_cyclic());
+        assert_eq!(group.exponent(), 6);
+
+        // Z_2 × Z_3 is cyclic, and decomposes back into the same factors
+        let group = GroupGenerators::abelian_from_invariant_factors(&[2, 3]).unwrap();
+        assert!(group.is_isomorphic_to(&GroupGenerators::generate_modulo_group_add(6).unwrap()));
+        assert_eq!(group.abelian_decomposition().unwrap().prime_power_orders, vec![(2, 1), (3, 1)]);
+
+        assert!(!GroupGenerators::abelian_from_invariant_factors(&[2, 2]).unwrap().is_cyclic());
+        assert_eq!(GroupGenerators::abelian_from_invariant_factors(&[]).unwrap().order(), 1);
+        assert!(GroupGenerators::abelian_from_invariant_factors(&[2, 0]).is_err());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,287 @@
+use crate::error::AbsaglError;
+use crate::groups::GroupElement;
+use crate::groups::permutation::{Permutation, PermutationError};
+
+
+/// A base and strong generating set of a permutation group, built by the Schreier-Sims algorithm.
+/// level i holds the base point b_i, the strong generators fixing b_0, ..., b_(i-1),
+/// and a transversal of the orbit of b_i under them.
+/// it never enumerates the group, so it works for groups far too large for `generate_subgroup`,
+/// e.g. S_20 is stored with a handful of generators and 20 small transversals.
+#[derive(Debug, Clone)]
+pub struct BSGS {
+    degree: usize,
+    levels: Vec<Level>,
+}
+
+#[derive(Debug, Clone)]
+struct Level {
+    base_point: usize,
+    generators: Vec<Permutation>,
+    // transversal[x] is some u with u(base_point) = x, for x in the orbit
+    transversal: Vec<Option<Permutation>>,
+    orbit: Vec<usize>,
+}
+
+impl Level {
+    fn new(base_point: usize, generators: Vec<Permutation>, degree: usize) -> Self {
+        let mut level = Level { base_point, generators, transversal: vec![None; degree], orbit: vec![] };
+        level.compute_orbit();
+        level
+    }
+
+    /// recomputes the orbit of the base point and its transversal with a BFS over the generators
+    fn compute_orbit(&mut self) {
+        let degree = self.transversal.len();
+        self.transversal = vec![None; degree];
+        self.transversal[self.base_point] = Some(Permutation::identity(degree));
+        self.orbit = vec![self.base_point];
+        let mut next = 0;
+        while next < self.orbit.len() {
+            let x = self.orbit[next];
+            next += 1;
+            let u = self.transversal[x].clone().expect("orbit points have a transversal element");
+            for s in &self.generators {
+                let y = s.mapping()[x];
+                if self.transversal[y].is_none() {
+                    self.transversal[y] = Some(s.op(&u));
+                    self.orbit.push(y);
+                }
+            }
+        }
+    }
+}
+
+impl BSGS {
+
+    /// Runs the Schreier-Sims algorithm on the generators.
+    /// returns `PermutationError::SizeNotMatch` if the generators act on different numbers of points,
+    /// an empty generating set gives the trivial group on zero points.
+    pub fn new(generators: &[Permutation]) -> Result<Self, AbsaglError> {
+        let degree = generators.first().map_or(0, |g| g.mapping().len());
+        if generators.iter().any(|g| g.mapping().len() != degree) {
+            log::error!("Generators must have the same size.");
+            return Err(PermutationError::SizeNotMatch)?;
+        }
+
+        let identity = Permutation::identity(degree);
+        let generators: Vec<Permutation> = generators.iter().filter(|g| **g != identity).cloned().collect();
+
+        // every generator must move some base point
+        let mut base: Vec<usize> = vec![];
+        for g in &generators {
+            if base.iter().all(|&b| g.mapping()[b] == b) {
+                base.push(first_moved_point(g));
+            }
+        }
+        let levels = base
+            .iter()
+            .enumerate()
+            .map(|(i, &b)| {
+                let fixing = generators
+                    .iter()
+                    .filter(|g| base[..i].iter().all(|&c| g.mapping()[c] == c))
+                    .cloned()
+                    .collect();
+                Level::new(b, fixing, degree)
+            })
+            .collect();
+        let mut bsgs = BSGS { degree, levels };
+
+        // from the deepest level up, check that every Schreier generator of level i sifts through the levels below,
+        // otherwise add the residue as a new strong generator and go back down to where it was added.
+        let mut i = bsgs.levels.len();
+        while i > 0 {
+            let level = i - 1;
+            match bsgs.failing_schreier_generator(level) {
+                None => i -= 1,
+                Some((residue, reached)) => {
+                    if reached == bsgs.levels.len() {
+                        let point = first_moved_point(&residue);
+                        bsgs.levels.push(Level::new(point, vec![], degree));
+                    }
+                    for l in (level + 1)..=reached {
+                        bsgs.levels[l].generators.push(residue.clone());
+                        bsgs.levels[l].compute_orbit();
+                    }
+                    i = reached + 1;
+                }
+            }
+        }
+        Ok(bsgs)
+    }
+
+    /// finds a Schreier generator `u_(s(x))^-1 s u_x` of the level that does not sift through the levels below,
+    /// returning its residue and the level where sifting stopped.
+    fn failing_schreier_generator(&self, level: usize) -> Option<(Permutation, usize)> {
+        let current = &self.levels[level];
+        for &x in &current.orbit {
+            let u_x = current.transversal[x].as_ref().expect("orbit points have a transversal element");
+            for s in &current.generators {
+                let u_sx = current.transversal[s.mapping()[x]].as_ref().expect("the orbit is closed");
+                let h = u_sx.inverse().op(&s.op(u_x));
+                let (residue, reached) = self.sift(h, level + 1);
+                if reached < self.levels.len() || residue != Permutation::identity(self.degree) {
+                    return Some((residue, reached));
+                }
+            }
+        }
+        None
+    }
+
+    /// Strips `g` through the levels starting at `from`, dividing by transversal elements
+    /// until the image of a base point is not in its orbit.
+    /// returns the residue and the level where it stopped, `levels.len()` if it went through all of them.
+    fn sift(&self, mut g: Permutation, from: usize) -> (Permutation, usize) {
+        for (i, level) in self.levels.iter().enumerate().skip(from) {
+            let x = g.mapping()[level.base_point];
+            match &level.transversal[x] {
+                Some(u) => g = u.inverse().op(&g),
+                None => return (g, i),
+            }
+        }
+        (g, self.levels.len())
+    }
+
+    /// Returns the number of points the group acts on
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Returns the base points b_0, b_1, ...
+    pub fn base(&self) -> Vec<usize> {
+        self.levels.iter().map(|level| level.base_point).collect()
+    }
+
+    /// Returns the strong generating set, the union of the generators of all levels without duplicates.
+    /// It holds the original non-identity generators and every Schreier residue added by `new`,
+    /// so for each i, the strong generators fixing b_0, ..., b_(i-1) generate that pointwise stabilizer.
+    pub fn strong_generators(&self) -> Vec<Permutation> {
+        let mut generators: Vec<Permutation> = vec![];
+        for g in self.levels.iter().flat_map(|level| &level.generators) {
+            if !generators.contains(g) {
+                generators.push(g.clone());
+            }
+        }
+        generators
+    }
+
+    /// Returns the group order, the product of the basic orbit lengths.
+    /// it is a u128 since the groups this is meant for easily overflow a u64, e.g. |S_21| > 2^64.
+    pub fn order(&self) -> u128 {
+        self.levels.iter().map(|level| level.orbit.len() as u128).product()
+    }
+
+    /// Checks if `p` is in the group by sifting it through the stabilizer chain,
+    /// in polynomial time in the degree. a permutation of a different size is never in the group.
+    pub fn contains(&self, p: &Permutation) -> bool {
+        if p.mapping().len() != self.degree {
+            return false;
+        }
+        let (residue, reached) = self.sift(p.clone(), 0);
+        reached == self.levels.len() && residue == Permutation::identity(self.degree)
+    }
+}
+
+fn first_moved_point(g: &Permutation) -> usize {
+    g.mapping()
+        .iter()
+        .enumerate()
+        .find(|&(i, &x)| i != x)
+        .map(|(i, _)| i)
+        .expect("a non-identity permutation moves some point")
+}
+
+
+#[cfg(test)]
+mod test_bsgs {
+    use super::*;
+
+    #[test]
+    fn test_bsgs_symmetric_group_order() {
+        for n in 2..=8 {
+            let generators = [
+                Permutation::from_cycles(&[vec![0, 1]], n).unwrap(),
+                Permutation::from_cycles(&[(0..n).collect()], n).unwrap(),
+            ];
+            let bsgs = BSGS::new(&generators).unwrap();
+            assert_eq!(bsgs.order(), (1..=n as u128).product::<u128>());
+            assert_eq!(bsgs.degree(), n);
+        }
+
+        // far beyond what BFS can enumerate
+        let generators = [
+            Permutation::from_cycles(&[vec![0, 1]], 25).unwrap(),
+            Permutation::from_cycles(&[(0..25).collect()], 25).unwrap(),
+        ];
+        assert_eq!(BSGS::new(&generators).unwrap().order(), (1..=25u128).product::<u128>());
+    }
+
+    #[test]
+    fn test_bsgs_matches_generate_subgroup() {
+        // A_5, generated by two 3-cycles
+        let generators = [
+            Permutation::from_cycles(&[vec![0, 1, 2]], 5).unwrap(),
+            Permutation::from_cycles(&[vec![2, 3, 4]], 5).unwrap(),
+        ];
+        let bsgs = BSGS::new(&generators).unwrap();
+        assert_eq!(bsgs.order(), 60);
+        let elements = Permutation::generate_subgroup(&generators).unwrap();
+        assert_eq!(elements.len(), 60);
+        for p in Permutation::generate_group(5).unwrap() {
+            assert_eq!(bsgs.contains(&p), p.is_even());
+        }
+
+        // D_4 acting on the corners of a square
+        let generators = [
+            Permutation::from_cycles(&[vec![0, 1, 2, 3]], 4).unwrap(),
+            Permutation::from_cycles(&[vec![1, 3]], 4).unwrap(),
+        ];
+        let bsgs = BSGS::new(&generators).unwrap();
+        assert_eq!(bsgs.order(), 8);
+        let elements = Permutation::generate_subgroup(&generators).unwrap();
+        for p in Permutation::generate_group(4).unwrap() {
+            assert_eq!(bsgs.contains(&p), elements.contains(&p));
+        }
+    }
+
+    #[test]
+    fn test_bsgs_strong_generators() {
+        let cases = [
+            vec![Permutation::from_cycles(&[vec![0, 1]], 5).unwrap(), Permutation::from_cycles(&[(0..5).collect()], 5).unwrap()],
+            vec![Permutation::from_cycles(&[vec![0, 1, 2]], 5).unwrap(), Permutation::from_cycles(&[vec![2, 3, 4]], 5).unwrap()],
+            vec![Permutation::from_cycles(&[vec![0, 1, 2, 3]], 4).unwrap(), Permutation::from_cycles(&[vec![1, 3]], 4).unwrap()],
+        ];
+        for generators in cases {
+            let bsgs = BSGS::new(&generators).unwrap();
+            let strong = bsgs.strong_generators();
+            let base = bsgs.base();
+            for (i, level) in bsgs.levels.iter().enumerate() {
+                // the strong generators fixing b_0, ..., b_(i-1) must give the full basic orbit of b_i
+                let fixing: Vec<Permutation> = strong
+                    .iter()
+                    .filter(|g| base[..i].iter().all(|&b| g.mapping()[b] == b))
+                    .cloned()
+                    .collect();
+                let stabilizer = Level::new(base[i], fixing, bsgs.degree());
+                assert_eq!(stabilizer.orbit.len(), level.orbit.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_bsgs_edge_cases() {
+        let trivial = BSGS::new(&[Permutation::identity(4)]).unwrap();
+        assert_eq!(trivial.order(), 1);
+        assert!(trivial.base().is_empty());
+        assert!(trivial.contains(&Permutation::identity(4)));
+        assert!(!trivial.contains(&Permutation::identity(3)));
+
+        let empty = BSGS::new(&[]).unwrap();
+        assert_eq!(empty.order(), 1);
+        assert_eq!(empty.degree(), 0);
+
+        let mismatched = [Permutation::identity(3), Permutation::identity(4)];
+        assert!(BSGS::new(&mismatched).is_err());
+    }
+}
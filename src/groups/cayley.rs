@@ -0,0 +1,222 @@
+use std::fmt;
+use std::error::Error;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::error::AbsaglError;
+use crate::groups::{CanonicalRepr, FiniteGroup, GroupElement};
+
+
+#[derive(Debug)]
+pub enum CayleyTableError {
+    NotSquare,
+    EntryOutOfRange,
+    NoIdentity,
+    NoInverse,
+    NotAssociative,
+}
+
+impl fmt::Display for CayleyTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CayleyTableError::NotSquare => write!(f, "The Cayley table must be a non-empty square table"),
+            CayleyTableError::EntryOutOfRange => write!(f, "A Cayley table entry is not the index of an element"),
+            CayleyTableError::NoIdentity => write!(f, "The Cayley table has no identity element"),
+            CayleyTableError::NoInverse => write!(f, "An element has no inverse in the Cayley table"),
+            CayleyTableError::NotAssociative => write!(f, "The Cayley table is not associative"),
+        }
+    }
+}
+
+impl Error for CayleyTableError {}
+
+
+/// An element of a group given only by its multiplication table, the element `i` stands for row `i`,
+/// so `i * j = table[i][j]`. every element of the group shares the same table behind an `Arc`,
+/// since group elements must be `Sync`.
+/// the table is validated once by `FiniteGroup::from_cayley_table`, the elements trust it afterwards.
+#[derive(Debug, Clone)]
+pub struct CayleyGroupElement {
+    index: usize,
+    table: Arc<Vec<Vec<usize>>>,
+}
+
+impl CayleyGroupElement {
+
+    /// Returns the index of the element, i.e. its row in the table
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the multiplication table the element belongs to
+    pub fn table(&self) -> &[Vec<usize>] {
+        &self.table
+    }
+
+    /// in a group the identity is the only element with `e * e = e`
+    fn identity_index(&self) -> usize {
+        (0..self.table.len())
+            .find(|&e| self.table[e][e] == e)
+            .expect("a validated Cayley table has an identity")
+    }
+
+    fn same_table(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.table, &other.table) || self.table == other.table
+    }
+}
+
+impl GroupElement for CayleyGroupElement {
+    /// Looks up `table[self][other]`, it panics if the elements come from different tables.
+    fn op(&self, other: &Self) -> Self {
+        assert!(self.same_table(other), "Cannot multiply elements of different Cayley tables");
+        CayleyGroupElement { index: self.table[self.index][other.index], table: self.table.clone() }
+    }
+
+    /// Finds the `j` in the row of `self` with `table[self][j]` the identity.
+    fn inverse(&self) -> Self {
+        let identity = self.identity_index();
+        let index = self.table[self.index]
+            .iter()
+            .position(|&k| k == identity)
+            .expect("a validated Cayley table has inverses");
+        CayleyGroupElement { index, table: self.table.clone() }
+    }
+}
+
+// comparing the tables is only needed when they are not shared, which is rare, so the hash only uses the index
+impl PartialEq for CayleyGroupElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.same_table(other)
+    }
+}
+
+impl Eq for CayleyGroupElement {}
+
+impl Hash for CayleyGroupElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+impl CanonicalRepr for CayleyGroupElement {
+    /// elements of the same table are identified by their index.
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        (self.index as u64).to_be_bytes().to_vec()
+    }
+}
+
+impl fmt::Display for CayleyGroupElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.index)
+    }
+}
+
+impl FiniteGroup<CayleyGroupElement> {
+
+    /// Creates the group on `0..n` whose multiplication table is `table`, i.e. `i * j = table[i][j]`.
+    /// the table is checked to be an n x n table of indices with an identity, inverses and an associative product,
+    /// the associativity check is O(n^3). the elements are ordered by index, so `cayley_table()` gives back `table`.
+    pub fn from_cayley_table(table: Vec<Vec<usize>>) -> Result<Self, AbsaglError> {
+        let n = table.len();
+        if n == 0 || table.iter().any(|row| row.len() != n) {
+            log::error!("The Cayley table must be a non-empty square table");
+            return Err(CayleyTableError::NotSquare)?;
+        }
+        if table.iter().flatten().any(|&k| k >= n) {
+            log::error!("Cayley table entries must be less than {}", n);
+            return Err(CayleyTableError::EntryOutOfRange)?;
+        }
+
+        let identity = match (0..n).find(|&e| (0..n).all(|i| table[e][i] == i && table[i][e] == i)) {
+            Some(e) => e,
+            None => {
+                log::error!("No row and column of the Cayley table act as the identity");
+                return Err(CayleyTableError::NoIdentity)?;
+            }
+        };
+        for (i, row) in table.iter().enumerate() {
+            if !(0..n).any(|j| row[j] == identity && table[j][i] == identity) {
+                log::error!("Element {} has no inverse", i);
+                return Err(CayleyTableError::NoInverse)?;
+            }
+        }
+        for a in 0..n {
+            for b in 0..n {
+                for c in 0..n {
+                    if table[table[a][b]][c] != table[a][table[b][c]] {
+                        log::error!("({0} * {1}) * {2} != {0} * ({1} * {2})", a, b, c);
+                        return Err(CayleyTableError::NotAssociative)?;
+                    }
+                }
+            }
+        }
+
+        let table = Arc::new(table);
+        let elements = (0..n).map(|index| CayleyGroupElement { index, table: table.clone() }).collect();
+        Ok(FiniteGroup::new(elements))
+    }
+}
+
+
+#[cfg(test)]
+mod test_cayley {
+    use super::*;
+    use crate::groups::{Group, GroupGenerators};
+
+    #[test]
+    fn test_cayley_group_from_table() {
+        // Z_4, with 0 as the identity
+        let table: Vec<Vec<usize>> = (0..4).map(|i| (0..4).map(|j| (i + j) % 4).collect()).collect();
+        let group = FiniteGroup::from_cayley_table(table.clone()).unwrap();
+        assert_eq!(group.order(), 4);
+        assert!(group.is_cyclic());
+        assert_eq!(group.cayley_table().unwrap(), table);
+        assert_eq!(group.elements()[1].inverse().index(), 3);
+        assert_eq!(group.elements()[3].op(&group.elements()[2]).index(), 1);
+        assert!(group.is_isomorphic_to(&GroupGenerators::generate_modulo_group_add(4).unwrap()));
+    }
+
+    #[test]
+    fn test_cayley_group_round_trip() {
+        // bring S_3 over through its table, the identity does not need to be element 0
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let group = FiniteGroup::from_cayley_table(s3.cayley_table().unwrap()).unwrap();
+        assert!(!group.is_abelian());
+        assert!(group.is_isomorphic_to(&s3));
+        assert_eq!(group.identity().index(), s3.elements().iter().position(|p| *p == s3.identity()).unwrap());
+    }
+
+    #[test]
+    fn test_cayley_table_validation() {
+        assert!(matches!(FiniteGroup::from_cayley_table(vec![]), Err(AbsaglError::CayleyTable(CayleyTableError::NotSquare))));
+        assert!(matches!(
+            FiniteGroup::from_cayley_table(vec![vec![0, 1], vec![1]]),
+            Err(AbsaglError::CayleyTable(CayleyTableError::NotSquare))
+        ));
+        assert!(matches!(
+            FiniteGroup::from_cayley_table(vec![vec![0, 2], vec![1, 0]]),
+            Err(AbsaglError::CayleyTable(CayleyTableError::EntryOutOfRange))
+        ));
+        assert!(matches!(
+            FiniteGroup::from_cayley_table(vec![vec![0, 0], vec![0, 0]]),
+            Err(AbsaglError::CayleyTable(CayleyTableError::NoIdentity))
+        ));
+        // 0 is the identity, but 1 * x is never 0
+        assert!(matches!(
+            FiniteGroup::from_cayley_table(vec![vec![0, 1, 2], vec![1, 1, 1], vec![2, 1, 0]]),
+            Err(AbsaglError::CayleyTable(CayleyTableError::NoInverse))
+        ));
+        // a Latin square with an identity, so every element has an inverse, but 1 * 1 = 0 can't happen in Z_5
+        let loop_table = vec![
+            vec![0, 1, 2, 3, 4],
+            vec![1, 0, 3, 4, 2],
+            vec![2, 4, 0, 1, 3],
+            vec![3, 2, 4, 0, 1],
+            vec![4, 3, 1, 2, 0],
+        ];
+        assert!(matches!(
+            FiniteGroup::from_cayley_table(loop_table),
+            Err(AbsaglError::CayleyTable(CayleyTableError::NotAssociative))
+        ));
+    }
+}
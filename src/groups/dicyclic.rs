@@ -0,0 +1,218 @@
+use std::fmt;
+use std::error::Error;
+
+use crate::error::AbsaglError;
+use crate::groups::{CanonicalRepr, CheckedOp, GroupElement};
+use crate::utils;
+
+
+#[derive(Debug)]
+pub enum DicyclicError {
+    SizeCannotBeZero,
+    SizeNotMatch,
+}
+
+impl fmt::Display for DicyclicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DicyclicError::SizeCannotBeZero => write!(f, "Size cannot be zero"),
+            DicyclicError::SizeNotMatch => write!(f, "Size mismatch error"),
+        }
+    }
+}
+
+impl Error for DicyclicError {}
+
+
+/// An element `a^k x^b` of the dicyclic group Dic_n = <a, x | a^2n = 1, x^2 = a^n, x a x^-1 = a^-1> of order 4n.
+/// for n = 2 this is the quaternion group Q8, with a = i and x = j.
+#[derive(Copy, Clone, PartialEq, Debug, Eq, Hash)]
+pub struct DicyclicElement {
+    exponent: usize, // The power k of a, in 0..2n
+    x: bool, // Whether the element has the factor x
+    n: usize,
+}
+
+impl GroupElement for DicyclicElement {
+
+    /// `a^i a^j x^b = a^(i + j) x^b`, and since `x a^j = a^-j x` and `x^2 = a^n`,
+    /// `a^i x a^j x^b = a^(i - j) x^(1 + b)`.
+    fn op(&self, other: &Self) -> Self {
+        if self.n != other.n {
+            panic!("Cannot operate on elements with different n values");
+        }
+        let m = 2 * self.n;
+        if !self.x {
+            return DicyclicElement { exponent: (self.exponent + other.exponent) % m, x: other.x, n: self.n };
+        }
+        let exponent = (self.exponent + m - other.exponent % m) % m;
+        if other.x {
+            DicyclicElement { exponent: (exponent + self.n) % m, x: false, n: self.n }
+        } else {
+            DicyclicElement { exponent, x: true, n: self.n }
+        }
+    }
+
+    /// Inverse of `a^k` is `a^-k`, and inverse of `a^k x` is `a^(k + n) x` since `(a^k x)^2 = a^n`.
+    fn inverse(&self) -> Self {
+        let m = 2 * self.n;
+        let exponent = if self.x { (self.exponent + self.n) % m } else { (m - self.exponent) % m };
+        DicyclicElement { exponent, x: self.x, n: self.n }
+    }
+}
+
+impl CheckedOp for DicyclicElement {
+    type Error = DicyclicError;
+
+    fn checked_op(&self, other: &Self) -> Result<Self, Self::Error> {
+        if self.n != other.n {
+            log::error!("Size mismatch: {} != {}", self.n, other.n);
+            Err(DicyclicError::SizeNotMatch)
+        } else {
+            Ok(self.op(other))
+        }
+    }
+}
+
+
+impl DicyclicElement {
+
+    /// Creates the element `a^exponent x^b` of Dic_n, where b is 1 if `x` is true.
+    /// This will not check if the size is zero.
+    pub fn new(exponent: usize, x: bool, n: usize) -> Self {
+        DicyclicElement { exponent, x, n }
+    }
+
+    /// Creates the element `a^exponent x^b` of Dic_n, reducing the exponent modulo 2n,
+    /// returns `DicyclicError::SizeCannotBeZero` if the size is zero.
+    pub fn try_new(exponent: usize, x: bool, n: usize) -> Result<Self, AbsaglError> {
+        if n == 0 {
+            log::error!("Size cannot be zero");
+            return Err(DicyclicError::SizeCannotBeZero)?;
+        }
+        Ok(DicyclicElement { exponent: exponent % (2 * n), x, n })
+    }
+
+    pub fn identity(n: usize) -> Self {
+        DicyclicElement { exponent: 0, x: false, n }
+    }
+
+    /// Returns n, the group has order 4n
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the power of a
+    pub fn exponent(&self) -> usize {
+        self.exponent
+    }
+
+    /// Returns whether the element has the factor x
+    pub fn has_x(&self) -> bool {
+        self.x
+    }
+
+    /// Returns the order of the element, `2n / gcd(2n, k)` for `a^k` and 4 for every `a^k x`.
+    pub fn order(&self) -> usize {
+        if self.x {
+            return 4;
+        }
+        if self.exponent == 0 {
+            1
+        } else {
+            2 * self.n / utils::gcd(2 * self.n, self.exponent)
+        }
+    }
+
+    /// Generate the whole dicyclic group Dic_n of order 4n.
+    pub fn generate_group(n: usize) -> Result<Vec<Self>, AbsaglError> {
+        if n == 0 {
+            log::error!("Size cannot be zero");
+            return Err(DicyclicError::SizeCannotBeZero)?;
+        }
+        Ok([false, true]
+            .iter()
+            .flat_map(|&x| (0..2 * n).map(move |exponent| DicyclicElement { exponent, x, n }))
+            .collect())
+    }
+}
+
+
+impl fmt::Display for DicyclicElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let a_part = if self.exponent > 0 { format!("a^{}", self.exponent) } else { "".to_string() };
+        let x_part = if self.x { "x" } else { "" };
+
+        if !self.x && self.exponent == 0 {
+            write!(f, "e") // Identity element
+        } else {
+            write!(f, "{}{}", a_part, x_part)
+        }
+    }
+}
+
+impl CanonicalRepr for DicyclicElement {
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        [
+            &self.exponent.to_be_bytes()[..],
+            &[self.x as u8],
+            &self.n.to_be_bytes()[..],
+        ]
+        .concat()
+    }
+}
+
+#[cfg(test)]
+mod test_dicyclics {
+    use super::*;
+
+    #[test]
+    fn test_dicyclic_relations() {
+        let n = 3;
+        let a = DicyclicElement::new(1, false, n);
+        let x = DicyclicElement::new(0, true, n);
+        let a_n = DicyclicElement::new(n, false, n);
+        // x^2 = a^n, x a x^-1 = a^-1
+        assert_eq!(x.op(&x), a_n);
+        assert_eq!(x.op(&a).op(&x.inverse()), a.inverse());
+        assert_eq!(a.op(&x), DicyclicElement::new(1, true, n));
+    }
+
+    #[test]
+    fn test_dicyclic_inverse_and_order() {
+        for g in DicyclicElement::generate_group(3).unwrap() {
+            assert_eq!(g.op(&g.inverse()), DicyclicElement::identity(3));
+            assert_eq!(g.inverse().op(&g), DicyclicElement::identity(3));
+        }
+        let orders: Vec<usize> = DicyclicElement::generate_group(2).unwrap().iter().map(|g| g.order()).collect();
+        assert_eq!(orders, vec![1, 4, 2, 4, 4, 4, 4, 4]);
+    }
+
+    #[test]
+    fn test_dicyclic_creation() {
+        assert_eq!(DicyclicElement::try_new(7, true, 3).unwrap(), DicyclicElement::new(1, true, 3));
+        match DicyclicElement::try_new(0, false, 0) {
+            Err(AbsaglError::Dicyclic(DicyclicError::SizeCannotBeZero)) => (),
+            _ => panic!("Expected SizeCannotBeZero error"),
+        }
+        assert_eq!(DicyclicElement::generate_group(3).unwrap().len(), 12);
+    }
+
+    #[test]
+    fn test_dicyclic_checked_op() {
+        let a = DicyclicElement::new(1, false, 2);
+        let b = DicyclicElement::new(1, false, 3);
+        assert!(a.checked_op(&a).is_ok());
+        match a.checked_op(&b) {
+            Err(DicyclicError::SizeNotMatch) => (),
+            _ => panic!("Expected size mismatch error"),
+        }
+    }
+
+    #[test]
+    fn test_dicyclic_display() {
+        assert_eq!(format!("{}", DicyclicElement::identity(2)), "e");
+        assert_eq!(format!("{}", DicyclicElement::new(0, true, 2)), "x");
+        assert_eq!(format!("{}", DicyclicElement::new(3, true, 2)), "a^3x");
+    }
+}
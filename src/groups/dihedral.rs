@@ -1,8 +1,10 @@
 use std::fmt;
 use std::error::Error;
+use std::ops::Mul;
 
 use crate::error::AbsaglError;
 use crate::groups::{CanonicalRepr, CheckedOp, GroupElement};
+use crate::groups::permutation::Permutation;
 use crate::utils;
 
 
@@ -10,7 +12,9 @@ use crate::utils;
 #[derive(Debug)]
 pub enum DihedralError {
     SizeCannotBeZero,
-    SizeNotMatch
+    SizeNotMatch,
+    /// The string is not one of the forms `e`, `s`, `r^k` or `sr^k`.
+    InvalidFormat(String),
     // Add more as needed
 }   
 
@@ -19,6 +23,7 @@ impl fmt::Display for DihedralError {
         match self {
             DihedralError::SizeCannotBeZero => write!(f, "Size cannot be zero"),
             DihedralError::SizeNotMatch => write!(f, "Size mismatch error"),
+            DihedralError::InvalidFormat(s) => write!(f, "Cannot parse '{}' as a dihedral element", s),
             // Handle other errors as needed
         }
     }
@@ -32,20 +37,44 @@ impl Error for DihedralError {}
 
 
 #[derive(Copy, Clone, PartialEq, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawDihedralElement"))]
 pub struct DihedralElement {
     rotation: usize, // Number of rotations
     reflection: bool, // Whether the element is a reflection
     n: usize, // Number of sides of the polygon
 }
 
+/// The serialized form of a `DihedralElement`, checked by `DihedralElement::try_new` when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawDihedralElement {
+    rotation: usize,
+    reflection: bool,
+    n: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawDihedralElement> for DihedralElement {
+    type Error = AbsaglError;
+
+    fn try_from(raw: RawDihedralElement) -> Result<Self, Self::Error> {
+        DihedralElement::try_new(raw.rotation, raw.reflection, raw.n)
+    }
+}
+
 impl GroupElement for DihedralElement {
 
+    /// An element with rotation k and reflection b stands for `r^k s^b`,
+    /// so `(r^i s^a)(r^j s^b) = r^(i + (-1)^a j) s^(a xor b)` since `s r = r^-1 s`.
     fn op(&self, other: &Self) -> Self {
         if self.n != other.n {
             panic!("Cannot operate on elements with different n values");
         }
         
-        let new_rotation = (self.rotation + other.rotation) % self.n;
+        // a reflection on the left reverses the direction of the right rotation
+        let other_rotation = if self.reflection { (self.n - other.rotation % self.n) % self.n } else { other.rotation };
+        let new_rotation = (self.rotation + other_rotation) % self.n;
         let new_reflection = self.reflection ^ other.reflection; // XOR for reflection
 
         DihedralElement {
@@ -57,10 +86,14 @@ impl GroupElement for DihedralElement {
 
    
 
+    /// Inverse of `r^k` is `r^-k`, and every reflection `r^k s` is its own inverse.
     fn inverse(&self) -> Self {
+        if self.reflection {
+            return *self;
+        }
         DihedralElement {
-            rotation: (self.n - self.rotation) % self.n,
-            reflection: self.reflection, // Inverse of reflection is itself
+            rotation: (self.n - self.rotation % self.n) % self.n,
+            reflection: false,
             n: self.n,
         }
     }
@@ -94,11 +127,12 @@ impl DihedralElement {
         }
     }
 
-    /// Creates a new DihedralElement with the given rotation and reflection, 
-    /// this will not check if the size is zero
+    /// Creates a new DihedralElement with the given rotation and reflection,
+    /// returns `DihedralError::SizeCannotBeZero` if the size is zero.
     pub fn try_new(rotation: usize, reflection: bool, n: usize) -> Result<Self, AbsaglError> {
         if n == 0 {
             log::error!("Size cannot be zero");
+            return Err(DihedralError::SizeCannotBeZero)?;
         }
         Ok(DihedralElement {
             rotation,
@@ -147,6 +181,60 @@ impl DihedralElement {
         }
     }
 
+    /// Parses the `Display` forms `e`, `s`, `r^k` and `sr^k` back into an element of D_n,
+    /// the exponent is reduced modulo n. since `sr^k = r^-k s`, `sr^k` has rotation `-k`.
+    /// returns `DihedralError::InvalidFormat` for anything else, or `SizeCannotBeZero` if n is zero.
+    pub fn from_str(s: &str, n: usize) -> Result<Self, AbsaglError> {
+        if n == 0 {
+            log::error!("Size cannot be zero");
+            return Err(DihedralError::SizeCannotBeZero)?;
+        }
+        let trimmed = s.trim();
+        if trimmed == "e" {
+            return Ok(Self::identity(n));
+        }
+        let (reflection, rest) = match trimmed.strip_prefix('s') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+        let rotation = if rest.is_empty() && reflection {
+            0
+        } else {
+            match rest.strip_prefix("r^").map(|k| k.parse::<usize>()) {
+                Some(Ok(k)) if reflection => (n - k % n) % n,
+                Some(Ok(k)) => k % n,
+                _ => {
+                    log::error!("Cannot parse '{}' as a dihedral element", s);
+                    return Err(DihedralError::InvalidFormat(s.to_string()))?;
+                }
+            }
+        };
+        Ok(DihedralElement { rotation, reflection, n })
+    }
+
+    /// Returns the permutation of the polygon vertices `0..n` the element induces,
+    /// the rotation r maps vertex v to v + 1 and the reflection s maps v to -v, both mod n.
+    /// this is a homomorphism D_n -> S_n (injective for n >= 3), so `a.op(&b).to_permutation() == a.to_permutation().op(&b.to_permutation())`.
+    pub fn to_permutation(&self) -> Permutation {
+        let mapping = (0..self.n)
+            .map(|v| {
+                let flipped = if self.reflection { (self.n - v) % self.n } else { v };
+                (flipped + self.rotation) % self.n
+            })
+            .collect();
+        Permutation::new(mapping)
+    }
+
+    /// Raises the element to the power `exp`, `exp == 0` gives the identity.
+    /// a rotation `r^k` gives `r^(k * exp)`, a reflection alternates between itself and the identity.
+    pub fn pow(&self, exp: u32) -> Self {
+        if self.reflection {
+            return if exp % 2 == 1 { *self } else { Self::identity(self.n) };
+        }
+        let rotation = ((self.rotation as u128 * exp as u128) % self.n as u128) as usize;
+        DihedralElement { rotation, reflection: false, n: self.n }
+    }
+
     /// Generate a whole dihedral group .
     pub fn generate_group(n: usize) -> Result<Vec<Self>, AbsaglError> {
         if n == 0 {
@@ -181,11 +269,30 @@ impl DihedralElement {
 
 
 // A nice display format.
+impl Mul for DihedralElement {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.op(&other)
+    }
+}
+
+// overload Mul for borrowed DihedralElement, same as `op` it panics when n differs.
+impl<'b> Mul<&'b DihedralElement> for &DihedralElement {
+    type Output = DihedralElement;
+
+    fn mul(self, rhs: &'b DihedralElement) -> Self::Output {
+        self.op(rhs)
+    }
+}
+
+/// A reflection with rotation k is `r^k s = s r^-k`, so it is written `sr^(n-k)`.
 impl fmt::Display for DihedralElement {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s_part = if self.reflection { "s" } else { "" };
-        let r_part = if self.rotation > 0 {
-            format!("r^{}", self.rotation)
+        let exponent = if self.reflection { (self.n - self.rotation % self.n) % self.n } else { self.rotation };
+        let r_part = if exponent > 0 {
+            format!("r^{}", exponent)
         } else {
             "".to_string()
         };
@@ -229,6 +336,14 @@ mod test_dihedrals {
         assert_eq!(element.n, 4);
     }
 
+    #[test]
+    fn test_dihedral_element_creation_zero_size() {
+        match DihedralElement::try_new(0, false, 0) {
+            Err(AbsaglError::Dihedral(DihedralError::SizeCannotBeZero)) => (),
+            _ => panic!("Expected SizeCannotBeZero error"),
+        }
+    }
+
     #[test]
     fn test_dihedral_element_identity() {
         let identity = DihedralElement::identity(5);
@@ -248,6 +363,15 @@ mod test_dihedrals {
         assert_eq!(identity.order(), 1); // Identity has order 1
     }
 
+    #[test]
+    fn test_dihedral_mul_operator() {
+        let r = DihedralElement::new(1, false, 4);
+        let s = DihedralElement::new(0, true, 4);
+        assert_eq!(r * s, r.op(&s));
+        let (rr, rs) = (&r, &s);
+        assert_eq!(rr * rs, r * s);
+    }
+
     #[test]
     fn test_dihedral_element_inverse() {
         let element = DihedralElement::try_new(1, false, 4).unwrap();
@@ -270,6 +394,87 @@ mod test_dihedrals {
         assert!(c.reflection);
     }
 
+    #[test]
+    fn test_dihedral_element_op_is_non_abelian() {
+        let r = DihedralElement::new(1, false, 4);
+        let s = DihedralElement::new(0, true, 4);
+        // s r = r^-1 s
+        assert_eq!(s.op(&r), r.inverse().op(&s));
+        assert_ne!(s.op(&r), r.op(&s));
+        // s r is displayed the way it is written
+        assert_eq!(s.op(&r).to_string(), "sr^1");
+        assert_eq!(r.op(&s).to_string(), "sr^3");
+        let sr = DihedralElement::new(1, true, 4);
+        assert_eq!(sr.op(&sr), DihedralElement::identity(4));
+        for g in DihedralElement::generate_group(4).unwrap() {
+            assert_eq!(g.op(&g.inverse()), DihedralElement::identity(4));
+        }
+    }
+
+    #[test]
+    fn test_dihedral_to_permutation() {
+        let r = DihedralElement::new(1, false, 4);
+        let s = DihedralElement::new(0, true, 4);
+        assert_eq!(r.to_permutation(), Permutation::new(vec![1, 2, 3, 0]));
+        assert_eq!(s.to_permutation(), Permutation::new(vec![0, 3, 2, 1]));
+        assert_eq!(DihedralElement::identity(4).to_permutation(), Permutation::identity(4));
+
+        let elements = DihedralElement::generate_group(4).unwrap();
+        for a in &elements {
+            for b in &elements {
+                assert_eq!(a.op(b).to_permutation(), a.to_permutation().op(&b.to_permutation()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_dihedral_from_str() {
+        assert_eq!(DihedralElement::from_str("e", 4).unwrap(), DihedralElement::identity(4));
+        assert_eq!(DihedralElement::from_str("s", 4).unwrap(), DihedralElement::new(0, true, 4));
+        assert_eq!(DihedralElement::from_str("r^0", 4).unwrap(), DihedralElement::identity(4));
+        assert_eq!(DihedralElement::from_str("r^5", 4).unwrap(), DihedralElement::new(1, false, 4));
+        // sr^3 = r^-3 s = r s
+        assert_eq!(DihedralElement::from_str(" sr^3 ", 4).unwrap(), DihedralElement::new(1, true, 4));
+        let r = DihedralElement::new(1, false, 4);
+        let s = DihedralElement::new(0, true, 4);
+        assert_eq!(DihedralElement::from_str("sr^1", 4).unwrap(), s.op(&r));
+
+        for element in DihedralElement::generate_group(5).unwrap() {
+            assert_eq!(DihedralElement::from_str(&element.to_string(), 5).unwrap(), element);
+        }
+
+        for bad in ["", "x", "r", "r^", "r^-1", "rs", "ss"] {
+            match DihedralElement::from_str(bad, 4) {
+                Err(AbsaglError::Dihedral(DihedralError::InvalidFormat(_))) => (),
+                _ => panic!("Expected InvalidFormat error for '{}'", bad),
+            }
+        }
+        assert!(DihedralElement::from_str("e", 0).is_err());
+    }
+
+    #[test]
+    fn test_dihedral_pow() {
+        let r = DihedralElement::new(1, false, 6);
+        assert_eq!(r.pow(0), DihedralElement::identity(6));
+        assert_eq!(r.pow(4), DihedralElement::new(4, false, 6));
+        assert_eq!(DihedralElement::new(4, false, 6).pow(3), DihedralElement::identity(6));
+
+        let sr = DihedralElement::new(2, true, 6);
+        assert_eq!(sr.pow(0), DihedralElement::identity(6));
+        assert_eq!(sr.pow(3), sr);
+        assert_eq!(sr.pow(2), DihedralElement::identity(6));
+
+        // pow agrees with repeated op and with the element order
+        for element in DihedralElement::generate_group(6).unwrap() {
+            let mut acc = DihedralElement::identity(6);
+            for exp in 0..8 {
+                assert_eq!(element.pow(exp), acc);
+                acc = acc.op(&element);
+            }
+            assert_eq!(element.pow(element.order() as u32), DihedralElement::identity(6));
+        }
+    }
+
     #[test]
     fn test_to_canonical_bytes() {
         let d1 = DihedralElement::try_new(1, false,9).unwrap();
@@ -291,8 +496,22 @@ mod test_dihedrals {
         let d = DihedralElement::try_new(1, false, 5).unwrap();
         let result = a.checked_op(&d);
         match result {
-            Err(DihedralError::SizeNotMatch) => assert!(true),
+            Err(DihedralError::SizeNotMatch) => (),
             _ => panic!("Expected size mismatch error"),
         }
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_dihedral_serde_round_trip() {
+        let d = DihedralElement::try_new(2, true, 5).unwrap();
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(serde_json::from_str::<DihedralElement>(&json).unwrap(), d);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_dihedral_serde_rejects_invalid() {
+        assert!(serde_json::from_str::<DihedralElement>(r#"{"rotation":0,"reflection":true,"n":0}"#).is_err());
+    }
+}
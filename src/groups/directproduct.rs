@@ -1,13 +1,15 @@
-use crate::groups::GroupElement;
+use crate::groups::{CanonicalRepr, FiniteGroup, GroupElement};
 use crate::groups::modulo::{Modulo, ModuloError};
 use crate::groups::CheckedOp;
 use crate::groups::Additive;
+use crate::homomorphism::Homomorphism;
 use std::fmt;
 use std::error::Error;
 
 /// Represents an element in a direct product of cyclic groups.
 /// Each component is an element of one of the factor groups.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DirectProductElement {
     pub components: Vec<Modulo<Additive>>,
 }
@@ -32,10 +34,24 @@ impl GroupElement for DirectProductElement {
     }
 }
 
+impl CanonicalRepr for DirectProductElement {
+    /// The component count followed by each component's bytes,
+    /// the count prefix keeps elements with a different number of components from colliding.
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        let mut bytes = (self.components.len() as u64).to_be_bytes().to_vec();
+        for c in &self.components {
+            bytes.extend(c.to_canonical_bytes());
+        }
+        bytes
+    }
+}
+
 #[derive(Debug)]
 pub enum DirectProductError {
     /// The operation failed because the elements have different numbers of components.
     DifferentComponentCount,
+    /// The requested component index is larger than the number of factors.
+    ComponentOutOfRange { index: usize, count: usize },
     /// An error occurred in one ofthe underlying modulo operations.
     Modulo(ModuloError),
 }
@@ -44,6 +60,7 @@ impl fmt::Display for DirectProductError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             DirectProductError::DifferentComponentCount => write!(f, "Direct product elements have different numbers of components"),
+            DirectProductError::ComponentOutOfRange { index, count } => write!(f, "Component {} is out of range for a product of {} factors", index, count),
             DirectProductError::Modulo(e) => write!(f, "A component-wise operation failed: {}", e),
         }
     }
@@ -84,6 +101,92 @@ impl CheckedOp for DirectProductElement {
 }
 
 
+/// An element `(a, b)` of the external direct product A × B of any two groups.
+/// unlike `DirectProductElement`, the factors don't need to be cyclic, e.g. S_3 × Z_2.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DirectProduct<A, B> {
+    pub first: A,
+    pub second: B,
+}
+
+impl<A: GroupElement, B: GroupElement> GroupElement for DirectProduct<A, B> {
+    /// The group operation is performed component-wise.
+    fn op(&self, other: &Self) -> Self {
+        DirectProduct {
+            first: self.first.op(&other.first),
+            second: self.second.op(&other.second),
+        }
+    }
+
+    /// The inverse is also found component-wise.
+    fn inverse(&self) -> Self {
+        DirectProduct {
+            first: self.first.inverse(),
+            second: self.second.inverse(),
+        }
+    }
+}
+
+impl<A: CanonicalRepr, B: CanonicalRepr> CanonicalRepr for DirectProduct<A, B> {
+    /// Concatenates the two byte representations, the first one is prefixed with its length
+    /// because the length of e.g. a permutation's bytes varies, so `(a, b)` and `(a', b')` can't collide.
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        let first = self.first.to_canonical_bytes();
+        let second = self.second.to_canonical_bytes();
+        [(first.len() as u64).to_be_bytes().to_vec(), first, second].concat()
+    }
+}
+
+impl<A: GroupElement, B: GroupElement> DirectProduct<A, B> {
+    /// Creates the element `(first, second)`
+    pub fn new(first: A, second: B) -> Self {
+        DirectProduct { first, second }
+    }
+
+    /// The canonical projection `A × B -> A`, `(a, b) ↦ a`.
+    pub fn projection_first() -> Homomorphism<Self, A, impl Fn(&Self) -> A> {
+        Homomorphism::new(|x: &Self| x.first.clone(), Some("projection onto the first factor".to_string()))
+    }
+
+    /// The canonical projection `A × B -> B`, `(a, b) ↦ b`.
+    pub fn projection_second() -> Homomorphism<Self, B, impl Fn(&Self) -> B> {
+        Homomorphism::new(|x: &Self| x.second.clone(), Some("projection onto the second factor".to_string()))
+    }
+
+    /// The inclusion `A -> A × B`, `a ↦ (a, e_B)`, `identity_b` is the identity of B.
+    pub fn inclusion_first(identity_b: B) -> Homomorphism<A, Self, impl Fn(&A) -> Self> {
+        Homomorphism::new(
+            move |a: &A| DirectProduct::new(a.clone(), identity_b.clone()),
+            Some("inclusion of the first factor".to_string()),
+        )
+    }
+
+    /// The inclusion `B -> A × B`, `b ↦ (e_A, b)`, `identity_a` is the identity of A.
+    pub fn inclusion_second(identity_a: A) -> Homomorphism<B, Self, impl Fn(&B) -> Self> {
+        Homomorphism::new(
+            move |b: &B| DirectProduct::new(identity_a.clone(), b.clone()),
+            Some("inclusion of the second factor".to_string()),
+        )
+    }
+
+    /// Forms the product group G × H from the Cartesian product of the elements of `g` and `h`.
+    pub fn product_group(g: &FiniteGroup<A>, h: &FiniteGroup<B>) -> FiniteGroup<Self> {
+        let elements = g
+            .elements()
+            .iter()
+            .flat_map(|a| h.elements().iter().map(move |b| DirectProduct::new(a.clone(), b.clone())))
+            .collect();
+        FiniteGroup::new(elements)
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for DirectProduct<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.first, self.second)
+    }
+}
+
+
 #[cfg(test)]
 mod test_direct_product {
     use super::*;
@@ -162,4 +265,92 @@ mod test_direct_product {
             _ => panic!("Expected DifferentModuli error"),
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_generic_direct_product_group() {
+        use crate::groups::{Group, GroupGenerators};
+
+        // S_3 × Z_2 is a non-abelian group of order 12
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let z2 = GroupGenerators::generate_modulo_group_add(2).unwrap();
+        let s3_z2 = DirectProduct::product_group(&s3, &z2);
+        assert_eq!(s3_z2.order(), 12);
+        assert!(s3_z2.is_closed());
+        assert!(!s3_z2.is_abelian());
+
+        // D_4 × Z_3
+        let d4 = GroupGenerators::generate_dihedral_group(4).unwrap();
+        let z3 = GroupGenerators::generate_modulo_group_add(3).unwrap();
+        assert_eq!(DirectProduct::product_group(&d4, &z3).order(), 24);
+
+        // Z_2 × Z_3 ≅ Z_6
+        let z6 = GroupGenerators::generate_modulo_group_add(6).unwrap();
+        assert!(DirectProduct::product_group(&z2, &z3).is_isomorphic_to(&z6));
+    }
+
+    #[test]
+    fn test_generic_direct_product_element() {
+        let a = DirectProduct::new(Modulo::<Additive>::new(1, 3), Modulo::<Additive>::new(1, 2));
+        assert_eq!(a.op(&a), DirectProduct::new(Modulo::new(2, 3), Modulo::new(0, 2)));
+        assert_eq!(a.op(&a.inverse()), DirectProduct::new(Modulo::new(0, 3), Modulo::new(0, 2)));
+        assert_eq!(format!("{}", a), "(1 (mod 3)+, 1 (mod 2)+)");
+
+        let b = DirectProduct::new(Modulo::<Additive>::new(2, 3), Modulo::<Additive>::new(1, 2));
+        assert_ne!(a.to_canonical_bytes(), b.to_canonical_bytes());
+    }
+
+    #[test]
+    fn test_direct_product_element_canonical_bytes() {
+        use crate::groups::factor::FactorGroup;
+
+        let element = |a: u64, b: u64| DirectProductElement {
+            components: vec![Modulo::<Additive>::new(a, 2), Modulo::<Additive>::new(b, 4)],
+        };
+        assert_eq!(element(1, 3).to_canonical_bytes().len(), 8 + 2 * 16);
+        assert_ne!(element(1, 3).to_canonical_bytes(), element(1, 2).to_canonical_bytes());
+
+        // Z_2 × Z_4 / <(0, 2)> has 4 cosets
+        let group = FiniteGroup::new((0..2).flat_map(|a| (0..4).map(move |b| element(a, b))).collect());
+        let subgroup = FiniteGroup::new(vec![element(0, 0), element(0, 2)]);
+        let factor = FactorGroup::try_new(&group, &subgroup).unwrap();
+        assert_eq!(factor.coset_partition().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_generic_direct_product_projection_and_inclusion() {
+        use crate::groups::{Group, GroupGenerators};
+        use crate::groups::permutation::Permutation;
+
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let z2 = GroupGenerators::generate_modulo_group_add(2).unwrap();
+        let product = DirectProduct::product_group(&s3, &z2);
+
+        // the kernel of the projection onto S_3 is {e} × Z_2, and the image is S_3
+        let p1 = DirectProduct::<Permutation, Modulo<Additive>>::projection_first();
+        let kernel = p1.kernel(&product, &Permutation::identity(3)).unwrap();
+        assert_eq!(kernel.order(), 2);
+        assert!(p1.is_surjective(&product, &s3).unwrap());
+        assert_eq!(product.order() / kernel.order(), p1.image(&product).unwrap().order());
+
+        let p2 = DirectProduct::<Permutation, Modulo<Additive>>::projection_second();
+        assert_eq!(p2.kernel(&product, &Modulo::new(0, 2)).unwrap().order(), 6);
+
+        let i1 = DirectProduct::<Permutation, Modulo<Additive>>::inclusion_first(Modulo::new(0, 2));
+        assert!(i1.is_injective(&s3));
+        assert!(product.is_subgroup(&i1.image(&s3).unwrap()));
+        let i2 = DirectProduct::<Permutation, Modulo<Additive>>::inclusion_second(Permutation::identity(3));
+        assert_eq!(i2.image(&z2).unwrap(), kernel);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_direct_product_element_serde_round_trip() {
+        let element = DirectProductElement {
+            components: vec![Modulo::<Additive>::new(1, 2), Modulo::<Additive>::new(2, 3)],
+        };
+        let json = serde_json::to_string(&element).unwrap();
+        assert_eq!(serde_json::from_str::<DirectProductElement>(&json).unwrap(), element);
+        // the components are checked like any other Modulo
+        assert!(serde_json::from_str::<DirectProductElement>(r#"{"components":[{"value":1,"modulus":0}]}"#).is_err());
+    }
+}
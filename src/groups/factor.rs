@@ -2,6 +2,7 @@
 use crate::error::AbsaglError;
 
 use crate::groups::{CanonicalRepr, CheckedOp, FiniteGroup, Group, GroupElement, GroupError};
+use crate::homomorphism::Homomorphism;
 
 
 use std::fmt::{self};
@@ -17,6 +18,8 @@ pub enum CosetError {
     InvalidSubgroup(GroupError),
     MixLeftAndRightCoset,
     DifferentSubgroup,
+    /// The left and right coset of the representative are different sets, so the side cannot be switched.
+    SidesDiffer,
     Element(Box<dyn Error + Send + Sync + 'static>),
     // You could add other coset-specific errors here later, e.g.:
     // RepresentativeNotInParentGroup,
@@ -28,6 +31,7 @@ impl fmt::Display for CosetError {
             CosetError::InvalidSubgroup(e) => write!(f, "Invalid subgroup: {}", e),
             CosetError::MixLeftAndRightCoset => write!(f, "Cannot mix left/right coset"),
             CosetError::DifferentSubgroup => write!(f, "cannot operation cosets from different subgroup"),
+            CosetError::SidesDiffer => write!(f, "The left and right coset of the representative differ"),
             CosetError::Element(e) => write!(f, "Underlying element error: {}", e),
             // Add more cases as you add more error variants
         }
@@ -66,7 +70,7 @@ impl<'a, T: GroupElement> PartialEq for Coset<'a, T> {
         }
         let a_inv = self.representative.inverse();
         let a_inv_b = a_inv.op(&other.representative);
-        self.subgroup.elements.contains(&a_inv_b)
+        self.subgroup.contains(&a_inv_b)
     }
 }
 impl<'a, T: GroupElement> Eq for Coset<'a, T> {}
@@ -97,9 +101,13 @@ where
 // This is where the magic happens! The logic is generic.
 impl<'a, T: GroupElement + CanonicalRepr> GroupElement for Coset<'a, T> {
 
-    /// Operation for cosets: (aN)(bN) = (ab)N, will panic if left coset op right coset, and vice versa
+    /// Operation for cosets: (aN)(bN) = (ab)N, will panic if left coset op right coset, and vice versa.
+    /// use `checked_op` to combine cosets without panicking, or `with_side` to realign one of them first.
     fn op(&self, other: &Self) -> Self {
-        assert_eq!(self.side, other.side, "cannot mix operation with left/right coset");
+        assert_eq!(
+            self.side, other.side,
+            "cannot mix operation with left/right coset, realign one side with `Coset::with_side` or use `checked_op`"
+        );
         Coset {
             representative: self.representative.op(&other.representative),
             subgroup: self.subgroup,
@@ -127,7 +135,8 @@ impl<'a, T: GroupElement + CanonicalRepr> GroupElement for Coset<'a, T> {
 impl<'a, T: GroupElement + CheckedOp + CanonicalRepr> CheckedOp for Coset<'a, T> {
     type Error = CosetError;
 
-    /// A fallible version of the group operation for cosets.
+    /// A fallible version of the group operation for cosets, this is the safe way to combine cosets.
+    /// returns `CosetError::MixLeftAndRightCoset` or `CosetError::DifferentSubgroup` instead of panicking.
     fn checked_op(&self, other: &Self) -> Result<Self, Self::Error> {
         if self.side != other.side {
             log::error!("cannot mix left/right coset for operation");
@@ -163,9 +172,9 @@ impl<'a, T: GroupElement + CanonicalRepr> Coset<'a, T> {
         }
 
         Ok(Coset {
-            representative: representative,
+            representative,
             subgroup,
-            side: side,
+            side,
             _marker: PhantomData,
         })
 
@@ -173,7 +182,7 @@ impl<'a, T: GroupElement + CanonicalRepr> Coset<'a, T> {
 
     /// enumerate a full coset based on representative
     pub fn enumerate_coset(&self) -> Vec<T> {
-        if self.subgroup.elements.contains(&self.representative) {
+        if self.subgroup.contains(&self.representative) {
             return self.subgroup.elements.clone();
         }
         // let mut elements = Vec::new();
@@ -201,6 +210,22 @@ impl<'a, T: GroupElement + CanonicalRepr> Coset<'a, T> {
         // FiniteGroup::new(elements)
     }
 
+    /// Returns the same coset viewed from `side`, e.g. realigns a right coset Ng to the left coset gN.
+    /// this only makes sense when both are the same set, which always holds if the subgroup is normal,
+    /// otherwise returns `CosetError::SidesDiffer`.
+    pub fn with_side(self, side: CosetSide) -> Result<Self, AbsaglError> {
+        if self.side == side {
+            return Ok(self);
+        }
+        let current: HashSet<T> = self.enumerate_coset().into_iter().collect();
+        let realigned = Coset { side, ..self };
+        if realigned.enumerate_coset().iter().any(|g| !current.contains(g)) {
+            log::error!("The left and right coset of the representative differ, cannot switch the side");
+            return Err(CosetError::SidesDiffer)?;
+        }
+        Ok(realigned)
+    }
+
     /// Finds the canonical representative of the coset.
     /// This is the element in the coset with the lexicographically smallest
     /// canonical byte representation.
@@ -223,6 +248,19 @@ impl<'a, T: GroupElement + CanonicalRepr> Coset<'a, T> {
 }
 
 
+impl<'a, T: GroupElement + CanonicalRepr + fmt::Display> fmt::Display for Coset<'a, T> {
+    /// Prints the canonical representative next to the subgroup, `gN` for a left coset and `Ng` for a right coset,
+    /// so every representative of the same coset prints the same.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let representative = self.get_canonical_representative();
+        match self.side {
+            CosetSide::Left => write!(f, "{}N", representative),
+            CosetSide::Right => write!(f, "N{}", representative),
+        }
+    }
+}
+
+
 #[derive(Debug)]
 /// FactorGroup struct. It borrows the groups it's built from.
 pub struct FactorGroup<'a, T: GroupElement> {
@@ -278,19 +316,7 @@ impl<'a, T: GroupElement + CanonicalRepr> Group<Coset<'a, T>> for FactorGroup<'a
     /// Checks if the factor group is abelian.
     fn is_abelian(&self) -> bool {
         // We can check this by generating all cosets and comparing them.
-        // First, get the unique cosets.
-        let mut unique_cosets: Vec<Coset<T>> = Vec::new();
-        for g in &self.group.elements {
-            let coset = Coset {
-                representative: g.clone(),
-                subgroup: self.normal_subgroup,
-                side: CosetSide::Left, // for factor group, use left coset
-                _marker: PhantomData,
-            };
-            if !unique_cosets.contains(&coset) {
-                unique_cosets.push(coset);
-            }
-        }
+        let unique_cosets = self.cosets();
 
         // Now, check the abelian property for all pairs.
         for a in &unique_cosets {
@@ -332,10 +358,42 @@ impl<'a,T: GroupElement + CanonicalRepr> FactorGroup<'a,T> {
             return Err(GroupError::NotSubgroup)?;
         }
 
-        Ok(FactorGroup { group: group, normal_subgroup: subgroup })
+        Ok(FactorGroup { group, normal_subgroup: subgroup })
+    }
+
+    /// Builds G/ker(f) from a homomorphism `f: G -> H`, the kernel is computed and checked to be normal
+    /// by `Homomorphism::kernel`. since a `FactorGroup` only borrows its normal subgroup,
+    /// the kernel is owned by the returned `OwnedFactorGroup`, call `factor_group()` on it to get G/ker(f).
+    pub fn from_kernel<H, F>(
+        hom: &Homomorphism<T, H, F>,
+        source: &'a FiniteGroup<T>,
+        identity_h: &H,
+    ) -> Result<OwnedFactorGroup<'a, T>, AbsaglError>
+    where
+        H: GroupElement,
+        F: Fn(&T) -> H,
+    {
+        let kernel = hom.kernel(source, identity_h)?;
+        Ok(OwnedFactorGroup::new(source, kernel))
+    }
+
+    /// Returns the distinct (left) cosets gN, i.e. the elements of G/N, one per coset
+    /// in the order their first representative appears in the group, deduped by hashing.
+    pub fn cosets(&self) -> Vec<Coset<'a, T>> {
+        let mut seen = HashSet::new();
+        self.group
+            .elements
+            .iter()
+            .map(|g| Coset {
+                representative: g.clone(),
+                subgroup: self.normal_subgroup,
+                side: CosetSide::Left, // for factor group, use left coset
+                _marker: PhantomData,
+            })
+            .filter(|coset| seen.insert(coset.clone()))
+            .collect()
     }
 
-    
     /// This function partitions the group `G` into disjoint cosets with respect
     /// to the normal subgroup `N`.
     ///
@@ -384,11 +442,38 @@ impl<'a,T: GroupElement + CanonicalRepr> FactorGroup<'a,T> {
 }
 
 
+/// A group together with an owned normal subgroup, e.g. a kernel computed on the fly,
+/// so the `FactorGroup` borrowing both can be built without a separate binding for the subgroup.
+#[derive(Debug)]
+pub struct OwnedFactorGroup<'a, T: GroupElement> {
+    group: &'a FiniteGroup<T>,
+    normal_subgroup: FiniteGroup<T>,
+}
+
+impl<'a, T: GroupElement + CanonicalRepr> OwnedFactorGroup<'a, T> {
+    /// Stores `normal_subgroup` with the group, this does not check that it is normal in `group`.
+    pub(crate) fn new(group: &'a FiniteGroup<T>, normal_subgroup: FiniteGroup<T>) -> Self {
+        OwnedFactorGroup { group, normal_subgroup }
+    }
+
+    /// Returns the factor group G/N, it borrows the normal subgroup stored in `self`.
+    pub fn factor_group(&self) -> FactorGroup<'_, T> {
+        FactorGroup::new(self.group, &self.normal_subgroup)
+    }
+
+    /// Returns the normal subgroup N
+    pub fn normal_subgroup(&self) -> &FiniteGroup<T> {
+        &self.normal_subgroup
+    }
+}
+
+
 #[cfg(test)]
 mod test_coset{
 
     use crate::groups::{modulo::Modulo, Additive};
     use crate::groups::permutation::{Permutation, PermutationError};
+    use crate::groups::dihedral::DihedralElement;
     use crate::groups::GroupGenerators;
     use super::*;
 
@@ -499,6 +584,42 @@ mod test_coset{
     }
 
 
+    #[test]
+    #[should_panic(expected = "Coset::with_side")]
+    fn test_coset_op_mixed_sides_panics() {
+        let a3 = GroupGenerators::generate_alternating_group(3).unwrap();
+        let g = Permutation::try_new(vec![1, 0, 2]).unwrap();
+        let left = Coset::new(g.clone(), &a3, CosetSide::Left).unwrap();
+        let right = Coset::new(g, &a3, CosetSide::Right).unwrap();
+        let _ = left.op(&right);
+    }
+
+    #[test]
+    fn test_coset_with_side() {
+        let g = Permutation::try_new(vec![1, 0, 2]).unwrap();
+        let h = Permutation::try_new(vec![0, 2, 1]).unwrap();
+
+        // A_3 is normal in S_3, so the right coset realigns to the left one and can be combined
+        let a3 = GroupGenerators::generate_alternating_group(3).unwrap();
+        let left = Coset::new(g.clone(), &a3, CosetSide::Left).unwrap();
+        let right = Coset::new(h.clone(), &a3, CosetSide::Right).unwrap();
+        match left.checked_op(&right) {
+            Err(CosetError::MixLeftAndRightCoset) => (),
+            _ => panic!("Expected MixLeftAndRightCoset error"),
+        }
+        let realigned = right.with_side(CosetSide::Left).unwrap();
+        assert!(left.checked_op(&realigned).is_ok());
+        assert_eq!(left, realigned);
+
+        // {e, (0 1)} is not normal, g' = (1 2) has different left and right cosets
+        let subgroup = FiniteGroup::try_new(vec![Permutation::identity(3), g]).unwrap();
+        let right = Coset::new(h, &subgroup, CosetSide::Right).unwrap();
+        match right.with_side(CosetSide::Left) {
+            Err(AbsaglError::Coset(CosetError::SidesDiffer)) => (),
+            _ => panic!("Expected SidesDiffer error"),
+        }
+    }
+
     #[test]
     fn test_coset_checked_op_fail_box_error() {
         let a = Permutation::try_new(vec![0,1,2]).unwrap();
@@ -615,6 +736,23 @@ mod test_coset{
 
     }
 
+    #[test]
+    fn test_coset_display() {
+        let d3 = GroupGenerators::generate_dihedral_group(3).expect("should generate group");
+        let s = DihedralElement::new(0, true, 3);
+        let subgroup = d3.cyclic_subgroup(&s);
+
+        // r and rs are in the same left coset, so both print the canonical representative
+        let r = DihedralElement::new(1, false, 3);
+        let left = Coset::new(r, &subgroup, CosetSide::Left).unwrap();
+        let same = Coset::new(r.op(&s), &subgroup, CosetSide::Left).unwrap();
+        assert_eq!(format!("{}", left), "r^1N");
+        assert_eq!(format!("{}", same), "r^1N");
+
+        let right = Coset::new(s.op(&r), &subgroup, CosetSide::Right).unwrap();
+        assert_eq!(format!("{}", right), "Nr^1");
+    }
+
     
 
 
@@ -641,7 +779,7 @@ mod test_factor_group {
     #[test]
     fn test_factor_group_create_fail_not_normal() {
         let e = Permutation::identity(3);
-        let g = Permutation::from_cycles(&vec![vec![0,1]], 3).expect("should create element");
+        let g = Permutation::from_cycles(&[vec![0,1]], 3).expect("should create element");
         let s3 = GroupGenerators::generate_permutation_group(3).expect("should generate group");
 
         let subgroup = FiniteGroup::try_new(vec![e, g])
@@ -736,6 +874,58 @@ mod test_factor_group {
         assert_eq!(cosets.len(), 2, "should have 6 cosets");
     }
 
+    #[test]
+    fn test_factor_group_cosets() {
+        let z6 = GroupGenerators::generate_modulo_group_add(6).expect("should generate group");
+        let e = Modulo::<Additive>::try_new(0, 6).expect("should create element");
+        let b = Modulo::<Additive>::try_new(2, 6).expect("should create element");
+        let c = Modulo::<Additive>::try_new(4, 6).expect("should create element");
+        let subgroup = FiniteGroup::try_new(vec![e, b, c]).expect("should create a FiniteGroup");
+        let factor_group = FactorGroup::try_new(&z6, &subgroup).expect("should create a FactorGroup");
+
+        let cosets = factor_group.cosets();
+        assert_eq!(cosets.len(), factor_group.order());
+        assert_eq!(cosets[0], factor_group.identity());
+        assert_ne!(cosets[0], cosets[1]);
+
+        let s4 = GroupGenerators::generate_permutation_group(4).expect("should generate group");
+        let a4 = GroupGenerators::generate_alternating_group(4).expect("should generate group");
+        assert_eq!(FactorGroup::new(&s4, &a4).cosets().len(), 2);
+    }
+
+    #[test]
+    fn test_factor_group_from_kernel() {
+        // sign: S_4 -> Z_2, the kernel is A_4
+        let s4 = GroupGenerators::generate_permutation_group(4).expect("should generate group");
+        let sign = Homomorphism::new(
+            |p: &Permutation| Modulo::<Additive>::new(if p.is_even() { 0 } else { 1 }, 2),
+            Some("sign".to_string()),
+        );
+        let identity = Modulo::<Additive>::new(0, 2);
+        let owned = FactorGroup::from_kernel(&sign, &s4, &identity).expect("should build S_4 / A_4");
+        assert_eq!(owned.normal_subgroup().order(), 12);
+        let factor_group = owned.factor_group();
+        assert_eq!(factor_group.order(), 2);
+        assert!(factor_group.is_abelian());
+
+        // a mapping that is not a homomorphism has a kernel that isn't a subgroup
+        let bad = Homomorphism::new(
+            |p: &Permutation| Modulo::<Additive>::new(p.mapping()[0] as u64 % 2, 2),
+            None,
+        );
+        assert!(FactorGroup::from_kernel(&bad, &s4, &identity).is_err());
+    }
+
+    #[test]
+    fn test_factor_group_abelianization() {
+        let s4 = GroupGenerators::generate_permutation_group(4).expect("should generate group");
+        let derived = s4.derived_subgroup();
+
+        let abelianization = FactorGroup::new(&s4, &derived);
+        assert_eq!(abelianization.order(), 2);
+        assert!(abelianization.is_abelian());
+    }
+
 
 
 
@@ -6,6 +6,7 @@ use crate::error::AbsaglError;
 use std::fmt;
 use std::error::Error;
 use std::marker::PhantomData;
+use std::ops::{Add, Mul};
 
 
 
@@ -36,12 +37,39 @@ impl Error for ModuloError {}
 /// Modulo struct for add/mul, Op can be Additive, Multiplicative,
 /// call it with `Modulo::<Additive>::method()`
 #[derive(Clone, Debug, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+// only value and modulus are stored, the operation comes from the type being deserialized into
+#[cfg_attr(feature = "serde", serde(try_from = "RawModulo"))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "", deserialize = "Op: ModuloOperation, Modulo<Op>: GroupElement")))]
 pub struct Modulo<Op> {
     value: u64,
     modulus: u64,
+    #[cfg_attr(feature = "serde", serde(skip))]
     _marker: PhantomData<Op>,
 }
 
+/// The serialized form of a `Modulo`, checked by `Modulo::try_new` when deserializing,
+/// so the value must be an element of the group being deserialized into.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawModulo {
+    value: u64,
+    modulus: u64,
+}
+
+#[cfg(feature = "serde")]
+impl<Op> TryFrom<RawModulo> for Modulo<Op>
+where
+    Op: ModuloOperation,
+    Modulo<Op>: GroupElement,
+{
+    type Error = AbsaglError;
+
+    fn try_from(raw: RawModulo) -> Result<Self, Self::Error> {
+        Modulo::try_new(raw.value, raw.modulus)
+    }
+}
+
 /// Defines properties associated with a modulo group operation.
 pub trait ModuloOperation : Sized where Modulo<Self>: GroupElement {
     /// The identity element for the operation (e.g., 0 for addition, 1 for multiplication).
@@ -71,7 +99,7 @@ impl ModuloOperation for Additive {
     fn order(value: u64, modulus: u64) -> u64 {
         
         // The order of an element in Z_n is n / gcd(n, value)
-        modulus / utils::gcd(modulus as usize, value as usize) as u64
+        modulus / utils::gcd_u64(modulus, value)
     }
     fn symbol() -> &'static str {
         "+"
@@ -87,13 +115,13 @@ impl ModuloOperation for Multiplicative {
     }
     /// Override: A value is valid for multiplication if gcd(value, modulus) == 1.
     fn is_valid(value: u64, modulus: u64) -> bool {
-        utils::gcd(value as usize, modulus as usize) == 1
+        utils::gcd_u64(value, modulus) == 1
     }
     fn order(value: u64, modulus: u64) -> u64 {
         let mut k = 1;
         let mut acc = value % modulus;
         while acc != 1 {
-            acc = (acc * value) % modulus;
+            acc = ((acc as u128 * value as u128) % modulus as u128) as u64;
             k += 1;
         }
         k
@@ -103,7 +131,7 @@ impl ModuloOperation for Multiplicative {
     }
     fn generate_group(modulus: u64) -> Result<Vec<Modulo<Self>>, AbsaglError> {
         (1..modulus)
-            .filter(|&k| utils::gcd(k as usize, modulus as usize) == 1)
+            .filter(|&k| utils::gcd_u64(k, modulus) == 1)
             .map(|k| Modulo::try_new(k, modulus))
             .collect()
     }
@@ -139,7 +167,8 @@ impl GroupElement for Modulo<Multiplicative> {
     fn op(&self, other: &Self) -> Self {
         assert_eq!(self.modulus, other.modulus, "Modulus must match");
         Modulo {
-            value: (self.value * other.value) % self.modulus,
+            // use a u128 intermediate so moduli above 2^32 don't overflow
+            value: ((self.value as u128 * other.value as u128) % self.modulus as u128) as u64,
             modulus: self.modulus,
             _marker: PhantomData,
         }
@@ -220,6 +249,22 @@ impl<Op> Modulo<Op> where Modulo<Op>: GroupElement {
         Ok(Modulo { value, modulus, _marker: PhantomData })
     }
 
+    /// Create a new Modulo element from a possibly negative residue, e.g. `-1 mod 5` is `4`.
+    /// the value is reduced into `0..modulus` first, then checked like `try_new`,
+    /// so for the multiplicative group it still returns an error if the residue is not a unit.
+    pub fn from_i64(value: i64, modulus: u64) -> Result<Self, AbsaglError>
+    where
+        Op: ModuloOperation,
+    {
+        if modulus == 0 {
+            log::error!("Modulus cannot be zero");
+            return Err(ModuloError::ZeroModulus)?;
+        }
+        // i128 so that every u64 modulus fits and the reduction can't overflow
+        let reduced = (value as i128).rem_euclid(modulus as i128) as u64;
+        Self::try_new(reduced, modulus)
+    }
+
     /// identity element for Modulo group
     pub fn identity(modulus: u64) -> Self 
     where
@@ -228,6 +273,12 @@ impl<Op> Modulo<Op> where Modulo<Op>: GroupElement {
         Modulo { value: Op::identity(), modulus, _marker: PhantomData }
     }
 
+    /// Returns the element in the usual notation, e.g. `2 (mod 5)`,
+    /// unlike `Display` which also appends the operation symbol, e.g. `2 (mod 5)+`.
+    pub fn to_math_string(&self) -> String {
+        format!("{} (mod {})", self.value, self.modulus)
+    }
+
     /// return value of the element
     pub fn value(&self) -> u64 {
         self.value
@@ -246,6 +297,54 @@ impl<Op> Modulo<Op> where Modulo<Op>: GroupElement {
         Op::order(self.value, self.modulus)
     }
 
+    /// Compute `self` to the power of `exp` using exponentiation by squaring,
+    /// for the multiplicative group this is modular exponentiation, for the additive group it is `exp * value`.
+    /// `exp == 0` returns the identity.
+    pub fn pow(&self, mut exp: u64) -> Self
+    where
+        Op: ModuloOperation,
+    {
+        let mut res = Self::identity(self.modulus);
+        if exp == 0 {
+            return res;
+        }
+
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp % 2 == 1 {
+                res = res.op(&base);
+            }
+            base = base.op(&base);
+            exp /= 2;
+        }
+        res
+    }
+
+    /// Returns the cyclic subgroup `<self>` as `[e, x, x^2, ...]`, by applying `op` from the identity until it cycles back,
+    /// without generating the whole group. e.g. 2 in Z_6 gives `[0, 2, 4]`.
+    pub fn cyclic_subgroup(&self) -> Vec<Self>
+    where
+        Op: ModuloOperation,
+    {
+        let identity = Self::identity(self.modulus);
+        let mut elements = vec![identity.clone()];
+        let mut current = self.clone();
+        while current != identity {
+            elements.push(current.clone());
+            current = current.op(self);
+        }
+        elements
+    }
+
+    /// The fallible counterpart of `a * b`, which panics on different moduli,
+    /// this returns `ModuloError::DifferentModuli` instead. it is a thin wrapper over `checked_op`.
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, ModuloError>
+    where
+        Op: ModuloOperation,
+    {
+        self.checked_op(other)
+    }
+
     /// Generate Z_n group elements
     pub fn generate_group(n: u64) -> Result<Vec<Self>, AbsaglError> 
     where 
@@ -262,6 +361,104 @@ impl<Op> Modulo<Op> where Modulo<Op>: GroupElement {
     // }
 }
 
+// overload Mul for both operations, this simply delegates to `op`, so mismatched moduli panic the same way.
+// `checked_mul` and `checked_add` are the non-panicking alternatives.
+impl<Op> Mul for Modulo<Op> where Modulo<Op>: GroupElement {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.op(&other)
+    }
+}
+
+impl<'b, Op> Mul<&'b Modulo<Op>> for &Modulo<Op> where Modulo<Op>: GroupElement {
+    type Output = Modulo<Op>;
+
+    fn mul(self, rhs: &'b Modulo<Op>) -> Self::Output {
+        self.op(rhs)
+    }
+}
+
+// for the additive group, `a + b` reads more naturally than `a * b`
+impl Add for Modulo<Additive> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.op(&other)
+    }
+}
+
+impl<'b> Add<&'b Modulo<Additive>> for &Modulo<Additive> {
+    type Output = Modulo<Additive>;
+
+    fn add(self, rhs: &'b Modulo<Additive>) -> Self::Output {
+        self.op(rhs)
+    }
+}
+
+impl Modulo<Additive> {
+
+    /// The fallible counterpart of `a + b`, which panics on different moduli,
+    /// this returns `ModuloError::DifferentModuli` instead. it is a thin wrapper over `checked_op`.
+    pub fn checked_add(&self, other: &Self) -> Result<Self, ModuloError> {
+        self.checked_op(other)
+    }
+
+    /// Returns `k · x`, i.e. `x` added to itself `k` times, the additive analogue of `pow`.
+    /// it is computed directly as `k * value mod n` with a u128 intermediate.
+    pub fn scalar_mul(&self, k: u64) -> Self {
+        let value = (k as u128 * self.value as u128 % self.modulus as u128) as u64;
+        Modulo { value, modulus: self.modulus, _marker: PhantomData }
+    }
+
+    /// Returns `k · x` for any integer `k`, a negative `k` gives `|k| · (-x)`.
+    pub fn scalar_mul_i64(&self, k: i64) -> Self {
+        let x = if k < 0 { self.inverse() } else { *self };
+        x.scalar_mul(k.unsigned_abs())
+    }
+}
+
+impl Modulo<Multiplicative> {
+
+    /// order of (Z/nZ)*, i.e. Euler's totient of `modulus`, together with the distinct primes dividing it
+    fn unit_group_order(modulus: u64) -> (u64, Vec<u64>) {
+        let totient = utils::euler_totient(modulus);
+        let primes = utils::prime_factorization(totient).iter().map(|&(p, _)| p).collect();
+        (totient, primes)
+    }
+
+    /// Check if the element generates the whole multiplicative group, i.e. `self.order()` equals φ(modulus).
+    /// instead of computing the order, this uses the equivalent test `self^(φ/p) != 1` for every prime p dividing φ.
+    pub fn is_primitive_root(&self) -> bool {
+        if self.modulus < 2 || !Multiplicative::is_valid(self.value, self.modulus) {
+            return false;
+        }
+        let (totient, primes) = Self::unit_group_order(self.modulus);
+        primes.iter().all(|&p| self.pow(totient / p).value != 1)
+    }
+
+    /// Returns the smallest primitive root mod `modulus`, i.e. a generator of (Z/nZ)*,
+    /// or `None` if the group is not cyclic, which happens unless modulus is 2, 4, p^k or 2p^k for an odd prime p.
+    pub fn primitive_root(modulus: u64) -> Option<Self> {
+        if modulus < 2 {
+            return None;
+        }
+        let factors = utils::prime_factorization(modulus);
+        let is_cyclic = match factors.as_slice() {
+            [(2, k)] => *k <= 2,
+            [(_, _)] => true,
+            [(2, 1), (_, _)] => true,
+            _ => false,
+        };
+        if !is_cyclic {
+            return None;
+        }
+        (1..modulus)
+            .map(|g| Modulo::new(g, modulus))
+            .find(|g| g.is_primitive_root())
+    }
+}
+
 impl<Op> fmt::Display for Modulo<Op>
 where
     Op: ModuloOperation, Modulo<Op>: GroupElement
@@ -330,6 +527,74 @@ mod test_modulos {
         assert_eq!(c.value, 1);
     }
 
+    #[test]
+    fn test_modulo_operator_overload() {
+        let a = Modulo::<Additive>::try_new(4, 7).unwrap();
+        let b = Modulo::<Additive>::try_new(5, 7).unwrap();
+        assert_eq!(a + b, a.op(&b));
+        let (ra, rb) = (&a, &b);
+        assert_eq!(ra + rb, a * b);
+
+        let c = Modulo::<Multiplicative>::try_new(3, 7).unwrap();
+        let d = Modulo::<Multiplicative>::try_new(5, 7).unwrap();
+        assert_eq!((c * d).value(), 1);
+        let (rc, rd) = (&c, &d);
+        assert_eq!(rc * rd, c.op(&d));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_modulo_mul_different_moduli_panics() {
+        let a = Modulo::<Multiplicative>::try_new(2, 5).unwrap();
+        let b = Modulo::<Multiplicative>::try_new(2, 7).unwrap();
+        let _ = a * b;
+    }
+
+    #[test]
+    fn test_modulo_pow() {
+        // 3^6 = 1 mod 7 by Fermat
+        let a = Modulo::<Multiplicative>::try_new(3, 7).unwrap();
+        assert_eq!(a.pow(0), Modulo::identity(7));
+        assert_eq!(a.pow(6).value(), 1);
+        assert_eq!(a.pow(5).value(), 5);
+        assert_eq!(a.pow(1_000_000_000_000).value(), a.pow(1_000_000_000_000 % 6).value());
+
+        let b = Modulo::<Additive>::try_new(4, 10).unwrap();
+        assert_eq!(b.pow(3).value(), 2);
+        assert_eq!(b.pow(0), Modulo::identity(10));
+    }
+
+    #[test]
+    fn test_modulo_op_mul_large_modulus() {
+        // the plain u64 product of these values overflows
+        let modulus = (1u64 << 33) + 17;
+        let a = Modulo::<Multiplicative>::new((1 << 33) + 5, modulus);
+        let b = Modulo::<Multiplicative>::new((1 << 32) + 3, modulus);
+        assert_eq!(a.op(&b).value(), 66);
+        assert_eq!(a.op(&a.inverse()).value(), 1);
+    }
+
+    #[test]
+    fn test_modulo_primitive_root() {
+        assert_eq!(Modulo::<Multiplicative>::primitive_root(7).unwrap().value(), 3);
+        assert_eq!(Modulo::<Multiplicative>::primitive_root(2).unwrap().value(), 1);
+        assert_eq!(Modulo::<Multiplicative>::primitive_root(4).unwrap().value(), 3);
+        assert_eq!(Modulo::<Multiplicative>::primitive_root(18).unwrap().value(), 5);
+        // (Z/8Z)* and (Z/15Z)* are not cyclic
+        assert!(Modulo::<Multiplicative>::primitive_root(8).is_none());
+        assert!(Modulo::<Multiplicative>::primitive_root(15).is_none());
+
+        // the generator reaches every element of the group
+        for n in [9, 25, 26, 27] {
+            let g = Modulo::<Multiplicative>::primitive_root(n).unwrap();
+            let group = Modulo::<Multiplicative>::generate_group(n).unwrap();
+            assert_eq!(g.order() as usize, group.len());
+            assert_eq!(group.iter().filter(|x| x.is_primitive_root()).count(), group.iter().filter(|x| x.order() as usize == group.len()).count());
+        }
+
+        assert!(!Modulo::<Multiplicative>::try_new(2, 7).unwrap().is_primitive_root());
+    }
+
     #[test]
     fn test_modulo_identity_add() {
         let id = Modulo::<Additive>::identity(5);
@@ -382,6 +647,48 @@ mod test_modulos {
         assert_eq!(a.order(), 1);
     }
 
+    #[test]
+    fn test_modulo_from_i64() {
+        assert_eq!(Modulo::<Additive>::from_i64(-1, 5).unwrap().value(), 4);
+        assert_eq!(Modulo::<Additive>::from_i64(-12, 5).unwrap().value(), 3);
+        assert_eq!(Modulo::<Additive>::from_i64(7, 5).unwrap().value(), 2);
+        assert_eq!(Modulo::<Additive>::from_i64(i64::MIN, u64::MAX).unwrap().value(), u64::MAX - (1 << 63));
+        assert!(Modulo::<Additive>::from_i64(-1, 0).is_err());
+
+        // -1 is a unit mod 6, -2 = 4 is not
+        assert_eq!(Modulo::<Multiplicative>::from_i64(-1, 6).unwrap().value(), 5);
+        assert!(Modulo::<Multiplicative>::from_i64(-2, 6).is_err());
+    }
+
+    #[test]
+    fn test_modulo_scalar_mul() {
+        let x = Modulo::<Additive>::try_new(3, 7).unwrap();
+        assert_eq!(x.scalar_mul(0), Modulo::<Additive>::identity(7));
+        assert_eq!(x.scalar_mul(5), x.pow(5));
+        assert_eq!(x.scalar_mul(5).value(), 1);
+        assert_eq!(x.scalar_mul_i64(-1), x.inverse());
+        assert_eq!(x.scalar_mul_i64(-5).value(), 6);
+        assert_eq!(x.scalar_mul_i64(i64::MIN), x.inverse().scalar_mul(1 << 63));
+
+        // no overflow near u64::MAX
+        let big = Modulo::<Additive>::try_new(u64::MAX - 1, u64::MAX).unwrap();
+        assert_eq!(big.scalar_mul(u64::MAX - 1).value(), 1);
+    }
+
+    #[test]
+    fn test_modulo_checked_add_and_mul() {
+        let a = Modulo::<Additive>::try_new(3, 5).unwrap();
+        let b = Modulo::<Additive>::try_new(4, 5).unwrap();
+        assert_eq!(a.checked_add(&b).unwrap(), a + b);
+        assert_eq!(a.checked_mul(&b).unwrap(), a * b);
+        assert!(matches!(a.checked_add(&Modulo::<Additive>::try_new(1, 6).unwrap()), Err(ModuloError::DifferentModuli)));
+
+        let c = Modulo::<Multiplicative>::try_new(3, 7).unwrap();
+        let d = Modulo::<Multiplicative>::try_new(5, 7).unwrap();
+        assert_eq!(c.checked_mul(&d).unwrap(), c * d);
+        assert!(matches!(c.checked_mul(&Modulo::<Multiplicative>::try_new(1, 8).unwrap()), Err(ModuloError::DifferentModuli)));
+    }
+
     #[test]
     fn test_modulo_checked_op_different_moduli_add() {
         let a = Modulo::<Additive>::try_new(1, 5).unwrap();
@@ -417,5 +724,42 @@ mod test_modulos {
         assert_eq!(format!("{}", a), "2 (mod 5)×");
     }
 
-    
-}
\ No newline at end of file
+    #[test]
+    fn test_modulo_cyclic_subgroup() {
+        let values = |elements: Vec<Modulo<Additive>>| elements.iter().map(|x| x.value()).collect::<Vec<_>>();
+        assert_eq!(values(Modulo::<Additive>::try_new(2, 6).unwrap().cyclic_subgroup()), vec![0, 2, 4]);
+        assert_eq!(values(Modulo::<Additive>::try_new(0, 6).unwrap().cyclic_subgroup()), vec![0]);
+        assert_eq!(Modulo::<Additive>::try_new(5, 6).unwrap().cyclic_subgroup().len(), 6);
+
+        // 2 generates the squares {1, 2, 4} in (Z/7Z)*
+        let subgroup = Modulo::<Multiplicative>::try_new(2, 7).unwrap().cyclic_subgroup();
+        assert_eq!(subgroup.iter().map(|x| x.value()).collect::<Vec<_>>(), vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn test_to_math_string() {
+        assert_eq!(Modulo::<Additive>::try_new(2, 5).unwrap().to_math_string(), "2 (mod 5)");
+        assert_eq!(Modulo::<Multiplicative>::try_new(2, 5).unwrap().to_math_string(), "2 (mod 5)");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_modulo_serde_round_trip() {
+        let a = Modulo::<Additive>::try_new(3, 7).unwrap();
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, r#"{"value":3,"modulus":7}"#);
+        assert_eq!(serde_json::from_str::<Modulo<Additive>>(&json).unwrap(), a);
+        // the operation is picked by the target type
+        let b = serde_json::from_str::<Modulo<Multiplicative>>(&json).unwrap();
+        assert_eq!(b, Modulo::<Multiplicative>::try_new(3, 7).unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_modulo_serde_rejects_invalid() {
+        assert!(serde_json::from_str::<Modulo<Additive>>(r#"{"value":3,"modulus":0}"#).is_err());
+        // 3 is not a unit mod 6
+        assert!(serde_json::from_str::<Modulo<Multiplicative>>(r#"{"value":3,"modulus":6}"#).is_err());
+        assert!(serde_json::from_str::<Modulo<Additive>>(r#"{"value":3,"modulus":6}"#).is_ok());
+    }
+}
@@ -0,0 +1,185 @@
+use std::fmt;
+use std::convert::Infallible;
+
+use crate::groups::{CanonicalRepr, CheckedOp, GroupElement};
+
+
+/// The units 1, i, j, k of the quaternion group.
+#[derive(Copy, Clone, PartialEq, Debug, Eq, Hash)]
+pub enum QuaternionUnit {
+    One,
+    I,
+    J,
+    K,
+}
+
+impl QuaternionUnit {
+    /// Multiply two units, returns `(negative, unit)` where `negative` is the sign of the product.
+    /// follows i² = j² = k² = ijk = -1.
+    fn mul(self, other: QuaternionUnit) -> (bool, QuaternionUnit) {
+        use QuaternionUnit::*;
+        match (self, other) {
+            (One, x) | (x, One) => (false, x),
+            (I, I) | (J, J) | (K, K) => (true, One),
+            (I, J) => (false, K),
+            (J, K) => (false, I),
+            (K, I) => (false, J),
+            (J, I) => (true, K),
+            (K, J) => (true, I),
+            (I, K) => (true, J),
+        }
+    }
+}
+
+
+/// An element ±1, ±i, ±j, ±k of the quaternion group Q8.
+#[derive(Copy, Clone, PartialEq, Debug, Eq, Hash)]
+pub struct QuaternionElement {
+    negative: bool, // Whether the element has a minus sign
+    unit: QuaternionUnit,
+}
+
+impl GroupElement for QuaternionElement {
+
+    fn op(&self, other: &Self) -> Self {
+        let (negative, unit) = self.unit.mul(other.unit);
+        QuaternionElement {
+            negative: negative ^ self.negative ^ other.negative,
+            unit,
+        }
+    }
+
+    /// Inverse of ±1 is itself, inverse of ±i is ∓i, and so on.
+    fn inverse(&self) -> Self {
+        match self.unit {
+            QuaternionUnit::One => *self,
+            unit => QuaternionElement { negative: !self.negative, unit },
+        }
+    }
+}
+
+impl CheckedOp for QuaternionElement {
+    // every pair of quaternion elements can be multiplied, so the operation never fails
+    type Error = Infallible;
+
+    fn checked_op(&self, other: &Self) -> Result<Self, Self::Error> {
+        Ok(self.op(other))
+    }
+}
+
+
+impl QuaternionElement {
+
+    /// Creates a new QuaternionElement, `negative` is the sign of the element.
+    pub fn new(negative: bool, unit: QuaternionUnit) -> Self {
+        QuaternionElement { negative, unit }
+    }
+
+    /// Returns the identity element 1
+    pub fn identity() -> Self {
+        QuaternionElement { negative: false, unit: QuaternionUnit::One }
+    }
+
+    /// Returns whether the element has a minus sign
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the unit of the element
+    pub fn unit(&self) -> QuaternionUnit {
+        self.unit
+    }
+
+    /// Returns the order of the element, 1 for 1, 2 for -1, and 4 for the others.
+    pub fn order(&self) -> usize {
+        match (self.negative, self.unit) {
+            (false, QuaternionUnit::One) => 1,
+            (true, QuaternionUnit::One) => 2,
+            _ => 4,
+        }
+    }
+
+    /// Generate the whole quaternion group Q8.
+    pub fn generate_group() -> Vec<Self> {
+        let units = [QuaternionUnit::One, QuaternionUnit::I, QuaternionUnit::J, QuaternionUnit::K];
+        [false, true]
+            .iter()
+            .flat_map(|&negative| units.iter().map(move |&unit| QuaternionElement { negative, unit }))
+            .collect()
+    }
+}
+
+
+impl fmt::Display for QuaternionElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.negative { "-" } else { "" };
+        let unit = match self.unit {
+            QuaternionUnit::One => "1",
+            QuaternionUnit::I => "i",
+            QuaternionUnit::J => "j",
+            QuaternionUnit::K => "k",
+        };
+        write!(f, "{}{}", sign, unit)
+    }
+}
+
+impl CanonicalRepr for QuaternionElement {
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        let unit_byte = match self.unit {
+            QuaternionUnit::One => 0u8,
+            QuaternionUnit::I => 1u8,
+            QuaternionUnit::J => 2u8,
+            QuaternionUnit::K => 3u8,
+        };
+        vec![self.negative as u8, unit_byte]
+    }
+}
+
+#[cfg(test)]
+mod test_quaternions {
+    use super::*;
+
+    #[test]
+    fn test_quaternion_relations() {
+        let minus_one = QuaternionElement::new(true, QuaternionUnit::One);
+        let i = QuaternionElement::new(false, QuaternionUnit::I);
+        let j = QuaternionElement::new(false, QuaternionUnit::J);
+        let k = QuaternionElement::new(false, QuaternionUnit::K);
+
+        // i² = j² = k² = ijk = -1
+        assert_eq!(i.op(&i), minus_one);
+        assert_eq!(j.op(&j), minus_one);
+        assert_eq!(k.op(&k), minus_one);
+        assert_eq!(i.op(&j).op(&k), minus_one);
+
+        // ij = k, ji = -k
+        assert_eq!(i.op(&j), k);
+        assert_eq!(j.op(&i), QuaternionElement::new(true, QuaternionUnit::K));
+    }
+
+    #[test]
+    fn test_quaternion_inverse() {
+        for q in QuaternionElement::generate_group() {
+            assert_eq!(q.op(&q.inverse()), QuaternionElement::identity());
+        }
+    }
+
+    #[test]
+    fn test_quaternion_order() {
+        let orders: Vec<usize> = QuaternionElement::generate_group().iter().map(|q| q.order()).collect();
+        assert_eq!(orders, vec![1, 4, 4, 4, 2, 4, 4, 4]);
+    }
+
+    #[test]
+    fn test_quaternion_display() {
+        let q = QuaternionElement::new(true, QuaternionUnit::J);
+        assert_eq!(format!("{}", q), "-j");
+        assert_eq!(format!("{}", QuaternionElement::identity()), "1");
+    }
+
+    #[test]
+    fn test_to_canonical_bytes() {
+        let q = QuaternionElement::new(true, QuaternionUnit::K);
+        assert_eq!(q.to_canonical_bytes(), vec![1, 3]);
+    }
+}
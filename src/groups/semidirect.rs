@@ -0,0 +1,191 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::error::AbsaglError;
+use crate::groups::{CanonicalRepr, FiniteGroup, GroupElement};
+use crate::homomorphism::HomomorphismError;
+
+
+/// The action `φ(h)(n)` of H on N, shared by all elements of a semidirect product.
+pub type Action<N, H> = Arc<dyn Fn(&H, &N) -> N + Send + Sync>;
+
+/// An element `(n, h)` of the semidirect product N ⋊_φ H, where H acts on N through `φ: H -> Aut(N)`.
+/// the product is twisted by the action, `(n1, h1)(n2, h2) = (n1 φ(h1)(n2), h1 h2)`,
+/// with the trivial action it is the direct product, e.g. Z_n ⋊ Z_2 with `φ(1)(k) = -k` is the dihedral group D_n.
+/// every element carries the action, so equality and hashing only look at `(n, h)`.
+#[derive(Clone)]
+pub struct SemidirectProductElement<N, H> {
+    n: N,
+    h: H,
+    action: Action<N, H>,
+}
+
+impl<N: GroupElement, H: GroupElement> SemidirectProductElement<N, H> {
+
+    /// Returns the component in the normal subgroup N
+    pub fn n(&self) -> &N {
+        &self.n
+    }
+
+    /// Returns the component in the acting group H
+    pub fn h(&self) -> &H {
+        &self.h
+    }
+
+    /// Forms N ⋊_φ H from the Cartesian product of the elements of `n_group` and `h_group`,
+    /// where `action(h, n)` is `φ(h)(n)`.
+    /// returns `HomomorphismError::PropertyNotHeld` if some `φ(h)` is not an automorphism of N,
+    /// or if `φ(h1 h2) != φ(h1) φ(h2)`, since the product would not be associative.
+    pub fn product_group<F>(n_group: &FiniteGroup<N>, h_group: &FiniteGroup<H>, action: F) -> Result<FiniteGroup<Self>, AbsaglError>
+    where
+        F: Fn(&H, &N) -> N + Send + Sync + 'static,
+    {
+        let ns = n_group.elements();
+        let hs = h_group.elements();
+        for h in hs {
+            let images: HashSet<N> = ns.iter().map(|n| action(h, n)).collect();
+            if images.len() != ns.len() || !images.iter().all(|image| ns.contains(image)) {
+                log::error!("The action of some element of H is not a bijection of N");
+                return Err(HomomorphismError::PropertyNotHeld)?;
+            }
+            let preserves_op = ns
+                .iter()
+                .all(|a| ns.iter().all(|b| action(h, &a.op(b)) == action(h, a).op(&action(h, b))));
+            if !preserves_op {
+                log::error!("The action of some element of H is not a homomorphism of N");
+                return Err(HomomorphismError::PropertyNotHeld)?;
+            }
+        }
+        let compatible = hs
+            .iter()
+            .all(|h1| hs.iter().all(|h2| ns.iter().all(|n| action(&h1.op(h2), n) == action(h1, &action(h2, n)))));
+        if !compatible {
+            log::error!("The action is not a homomorphism from H to Aut(N)");
+            return Err(HomomorphismError::PropertyNotHeld)?;
+        }
+
+        let action: Action<N, H> = Arc::new(action);
+        let elements = ns
+            .iter()
+            .flat_map(|n| {
+                hs.iter().map({
+                    let action = action.clone();
+                    move |h| SemidirectProductElement { n: n.clone(), h: h.clone(), action: action.clone() }
+                })
+            })
+            .collect();
+        Ok(FiniteGroup::new(elements))
+    }
+}
+
+impl<N: GroupElement, H: GroupElement> GroupElement for SemidirectProductElement<N, H> {
+    /// `(n1, h1)(n2, h2) = (n1 φ(h1)(n2), h1 h2)`
+    fn op(&self, other: &Self) -> Self {
+        SemidirectProductElement {
+            n: self.n.op(&(self.action)(&self.h, &other.n)),
+            h: self.h.op(&other.h),
+            action: self.action.clone(),
+        }
+    }
+
+    /// `(n, h)^-1 = (φ(h^-1)(n^-1), h^-1)`
+    fn inverse(&self) -> Self {
+        let h_inverse = self.h.inverse();
+        SemidirectProductElement {
+            n: (self.action)(&h_inverse, &self.n.inverse()),
+            h: h_inverse,
+            action: self.action.clone(),
+        }
+    }
+}
+
+impl<N: PartialEq, H: PartialEq> PartialEq for SemidirectProductElement<N, H> {
+    fn eq(&self, other: &Self) -> bool {
+        self.n == other.n && self.h == other.h
+    }
+}
+
+impl<N: Eq, H: Eq> Eq for SemidirectProductElement<N, H> {}
+
+impl<N: Hash, H: Hash> Hash for SemidirectProductElement<N, H> {
+    fn hash<S: Hasher>(&self, state: &mut S) {
+        self.n.hash(state);
+        self.h.hash(state);
+    }
+}
+
+impl<N: fmt::Debug, H: fmt::Debug> fmt::Debug for SemidirectProductElement<N, H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SemidirectProductElement").field("n", &self.n).field("h", &self.h).finish_non_exhaustive()
+    }
+}
+
+impl<N: CanonicalRepr, H: CanonicalRepr> CanonicalRepr for SemidirectProductElement<N, H> {
+    /// Same layout as `DirectProduct`, the bytes of n prefixed with their length, then the bytes of h.
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        let n = self.n.to_canonical_bytes();
+        let h = self.h.to_canonical_bytes();
+        [(n.len() as u64).to_be_bytes().to_vec(), n, h].concat()
+    }
+}
+
+impl<N: fmt::Display, H: fmt::Display> fmt::Display for SemidirectProductElement<N, H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.n, self.h)
+    }
+}
+
+
+#[cfg(test)]
+mod test_semidirect_product {
+    use super::*;
+    use crate::groups::modulo::Modulo;
+    use crate::groups::{Additive, Group, GroupGenerators};
+
+    #[test]
+    fn test_semidirect_product_dihedral() {
+        // Z_4 ⋊ Z_2, where 1 acts by negation, is D_4
+        let z4 = GroupGenerators::generate_modulo_group_add(4).unwrap();
+        let z2 = GroupGenerators::generate_modulo_group_add(2).unwrap();
+        let group = SemidirectProductElement::product_group(&z4, &z2, |h: &Modulo<Additive>, n: &Modulo<Additive>| {
+            if h.value() == 1 { n.inverse() } else { *n }
+        }).unwrap();
+        assert_eq!(group.order(), 8);
+        assert!(group.is_closed());
+        assert!(!group.is_abelian());
+        assert!(group.is_isomorphic_to(&GroupGenerators::generate_dihedral_group(4).unwrap()));
+
+        let x = &group.elements()[3];
+        assert_eq!(x.op(&x.inverse()), group.identity());
+        assert_eq!(format!("{}", group.identity()), format!("({}, {})", z4.identity(), z2.identity()));
+    }
+
+    #[test]
+    fn test_semidirect_product_dicyclic_and_trivial_action() {
+        // Z_3 ⋊ Z_4, where the generator of Z_4 acts by negation, is the dicyclic group Dic_3
+        let z3 = GroupGenerators::generate_modulo_group_add(3).unwrap();
+        let z4 = GroupGenerators::generate_modulo_group_add(4).unwrap();
+        let group = SemidirectProductElement::product_group(&z3, &z4, |h: &Modulo<Additive>, n: &Modulo<Additive>| {
+            if h.value() % 2 == 1 { n.inverse() } else { *n }
+        }).unwrap();
+        assert!(group.is_isomorphic_to(&GroupGenerators::generate_dicyclic(3).unwrap()));
+
+        // the trivial action gives the direct product Z_3 × Z_4 = Z_12
+        let group = SemidirectProductElement::product_group(&z3, &z4, |_: &Modulo<Additive>, n: &Modulo<Additive>| *n).unwrap();
+        assert!(group.is_cyclic());
+    }
+
+    #[test]
+    fn test_semidirect_product_invalid_action() {
+        let z3 = GroupGenerators::generate_modulo_group_add(3).unwrap();
+        let z2 = GroupGenerators::generate_modulo_group_add(2).unwrap();
+        // n ↦ n + 1 is not a homomorphism of Z_3
+        let shift = |_: &Modulo<Additive>, n: &Modulo<Additive>| n.op(&Modulo::<Additive>::new(1, 3));
+        assert!(SemidirectProductElement::product_group(&z3, &z2, shift).is_err());
+        // every element acting by negation is not a homomorphism to Aut(N), φ(1 + 1) = -id but φ(1)φ(1) = id
+        let negate_all = |_: &Modulo<Additive>, n: &Modulo<Additive>| n.inverse();
+        assert!(SemidirectProductElement::product_group(&z3, &z3, negate_all).is_err());
+    }
+}
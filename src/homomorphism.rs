@@ -1,7 +1,8 @@
 use std::marker::PhantomData;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
-use crate::{error::AbsaglError, groups::{CheckedOp, FiniteGroup, Group, GroupElement}};
+use crate::{error::AbsaglError, groups::{CanonicalRepr, CheckedOp, FiniteGroup, Group, GroupElement, GroupError}};
+use crate::groups::factor::{Coset, FactorGroup, OwnedFactorGroup};
 
 
 /// Defines errors that can occur when creating a homomorphism.
@@ -10,12 +11,18 @@ pub enum HomomorphismError {
     /// The provided mapping does not satisfy the homomorphism property
     /// f(a * b) = f(a) * f(b) for some a, b.
     PropertyNotHeld,
+    /// An element of the source group has no image in the given table.
+    MissingImage,
+    /// The homomorphism is not bijective, so it has no inverse.
+    NotBijective,
 }
 
 impl fmt::Display for HomomorphismError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             HomomorphismError::PropertyNotHeld => write!(f, "The mapping does not satisfy the homomorphism property"),
+            HomomorphismError::MissingImage => write!(f, "An element of the source group has no image in the table"),
+            HomomorphismError::NotBijective => write!(f, "The homomorphism is not bijective"),
         }
     }
 }
@@ -83,7 +90,7 @@ where
     pub fn new(mapping_fn: F, description: Option<String>) -> Self {
         Self {
             mapping: mapping_fn,
-            description: description,
+            description,
             _source_marker: PhantomData,
             _target_marker: PhantomData,
         }
@@ -142,11 +149,15 @@ where
     /// Computes the kernel of the homomorphism.
     /// The kernel is the set {g in G | f(g) = id_H}.
     ///
+    /// The kernel of a genuine homomorphism is always a normal subgroup, so this verifies
+    /// that it is closed and normal in the source group, and returns an error otherwise,
+    /// which means the mapping is not actually a homomorphism.
     /// # Arguments
     /// * `source_group`: A reference to the source group G.
     /// * `identity_h`: The identity element of the target group H.
     /// # Returns
     /// A `Result` containing the kernel as a `FiniteGroup<G>` on success,
+    /// `GroupError::NotClosed` or `GroupError::NotNormalSubgroup` on failure.
     pub fn kernel(&self, source_group: &FiniteGroup<G>, identity_h: &H) -> Result<FiniteGroup<G>, AbsaglError> {
         let kernel_elements: Vec<G> = source_group
             .elements()
@@ -155,7 +166,12 @@ where
             .cloned()
             .collect();
 
-        FiniteGroup::try_new(kernel_elements)
+        let kernel = FiniteGroup::try_new(kernel_elements)?;
+        if !source_group.is_normal(&kernel) {
+            log::error!("The kernel is not a normal subgroup, the mapping is not a homomorphism");
+            return Err(GroupError::NotNormalSubgroup)?;
+        }
+        Ok(kernel)
     }
 
 
@@ -179,6 +195,23 @@ where
     }
 
 
+    /// Computes the cokernel `H / im(f)`, the image must be a normal subgroup of `target`,
+    /// which always holds when H is abelian, e.g. for the cyclic modulo groups.
+    /// returns `GroupError::NotContainedOrNotClosed` if the image is not a subgroup of `target`,
+    /// or `GroupError::NotNormalSubgroup` if it is not normal. since a `FactorGroup` only borrows its normal subgroup,
+    /// the image is owned by the returned `OwnedFactorGroup`, call `factor_group()` on it to get H / im(f).
+    pub fn cokernel<'a>(&self, source: &FiniteGroup<G>, target: &'a FiniteGroup<H>) -> Result<OwnedFactorGroup<'a, H>, AbsaglError>
+    where
+        H: CanonicalRepr,
+    {
+        let image = self.image(source)?;
+        if !target.is_normal_subgroup(&image)? {
+            log::error!("The image is not a normal subgroup of the target, the cokernel is not a group");
+            return Err(GroupError::NotNormalSubgroup)?;
+        }
+        Ok(OwnedFactorGroup::new(target, image))
+    }
+
    
     /// Checks if the homomorphism is injective (a monomorphism).
     ///
@@ -248,15 +281,108 @@ where
     ) -> Result<bool, AbsaglError> {
         Ok(self.is_injective(source_group) && self.is_surjective(source_group, target_group)?)
     }
+
+    /// Computes the pieces of the first isomorphism theorem `G/ker(f) ≅ im(f)`.
+    ///
+    /// the kernel is computed and verified to be normal in `source`, then the image is computed,
+    /// and the induced map `gN ↦ f(g)` is checked to be a bijection from the cosets of the kernel onto the image.
+    /// the returned `FirstIsomorphism` gives access to `G/ker(f)` and the induced isomorphism.
+    /// # Arguments
+    /// * `source`: A reference to the source group G.
+    /// * `identity_h`: The identity element of the target group H.
+    pub fn factor_through<'a>(&self, source: &'a FiniteGroup<G>, identity_h: &H) -> Result<FirstIsomorphism<'a, G, H>, AbsaglError>
+    where
+        G: CanonicalRepr,
+    {
+        // the kernel is verified to be a normal subgroup
+        let kernel = self.kernel(source, identity_h)?;
+        let image = self.image(source)?;
+        let images: HashMap<G, H> = source.elements().iter().map(|g| (g.clone(), self.apply(g))).collect();
+
+        // the induced map is well defined and injective iff f is constant on each coset and differs between cosets
+        let cosets = FactorGroup::new(source, &kernel).coset_partition()?;
+        let mut seen = HashSet::with_capacity(cosets.len());
+        for coset in &cosets {
+            let value = &images[&coset[0]];
+            if coset.iter().any(|g| images[g] != *value) || !seen.insert(value.clone()) {
+                log::error!("The induced map G/ker(f) -> im(f) is not a bijection");
+                return Err(HomomorphismError::PropertyNotHeld)?;
+            }
+        }
+        if seen.len() != image.order() {
+            log::error!("The induced map G/ker(f) -> im(f) is not a bijection");
+            return Err(HomomorphismError::PropertyNotHeld)?;
+        }
+
+        Ok(FirstIsomorphism { source, kernel, image, images })
+    }
+
+    /// Builds the inverse `f⁻¹: H -> G` of a bijective homomorphism by inverting its element table.
+    /// returns `HomomorphismError::NotBijective` if `f` is not an isomorphism from `source` onto `target`.
+    pub fn invert(&self, source: &FiniteGroup<G>, target: &FiniteGroup<H>) -> Result<BoxedHomomorphism<H, G>, AbsaglError>
+    where
+        G: 'static,
+        H: 'static,
+    {
+        if !self.is_isomorphism(source, target)? {
+            log::error!("Cannot invert a homomorphism that is not bijective");
+            return Err(HomomorphismError::NotBijective)?;
+        }
+        let table: HashMap<H, G> = source.elements().iter().map(|g| (self.apply(g), g.clone())).collect();
+        let mapping: Box<dyn Fn(&H) -> G> = Box::new(move |h: &H| table[h].clone());
+        let description = self.description.as_ref().map(|desc| format!("({})⁻¹", desc));
+        Ok(Homomorphism::new(mapping, description))
+    }
 }
 
 
+/// The first isomorphism theorem `G/ker(f) ≅ im(f)` for a homomorphism `f: G -> H`, built by `Homomorphism::factor_through`.
+/// it owns the kernel and the image, and keeps the values of `f` to evaluate the induced isomorphism `gN ↦ f(g)`.
+pub struct FirstIsomorphism<'a, G: GroupElement, H: GroupElement> {
+    source: &'a FiniteGroup<G>,
+    pub kernel: FiniteGroup<G>,
+    pub image: FiniteGroup<H>,
+    images: HashMap<G, H>,
+}
+
+impl<'a, G: GroupElement + CanonicalRepr, H: GroupElement> FirstIsomorphism<'a, G, H> {
+    /// The factor group G/ker(f), it borrows the kernel stored in `self`.
+    pub fn factor_group(&self) -> FactorGroup<'_, G> {
+        FactorGroup::new(self.source, &self.kernel)
+    }
+
+    /// The induced isomorphism G/ker(f) -> im(f), `gN ↦ f(g)`, which doesn't depend on the representative g.
+    pub fn induced(&self, coset: &Coset<'_, G>) -> H {
+        self.images[&coset.get_canonical_representative()].clone()
+    }
+}
+
+impl<G: GroupElement> Homomorphism<G, G, fn(&G) -> G> {
+    /// The identity homomorphism `G -> G`, `g ↦ g`.
+    pub fn identity() -> Self {
+        Homomorphism::new(G::clone, Some("identity".to_string()))
+    }
+}
+
 // Implementation block for endomorphisms (homomorphisms from a group to itself)
 impl<G, F> Homomorphism<G, G, F>
 where
     G: GroupElement,
     F: Fn(&G) -> G,
 {
+    /// Checks if the map is an endomorphism of `group`, i.e. it maps `group` into itself
+    /// and satisfies `f(a * b) = f(a) * f(b)` for all pairs, in O(n^2).
+    /// unlike `is_automorphism`, it never fails, a map that leaves the group is simply not an endomorphism.
+    pub fn is_endomorphism(&self, group: &FiniteGroup<G>) -> bool {
+        let images: HashMap<&G, G> = group.elements().iter().map(|g| (g, self.apply(g))).collect();
+        if images.values().any(|image| !group.contains(image)) {
+            return false;
+        }
+        group.elements().iter().all(|a| {
+            group.elements().iter().all(|b| self.apply(&a.op(b)) == images[a].op(&images[b]))
+        })
+    }
+
     /// Checks if the homomorphism is an automorphism (isomorphism from G to G).
     /// 
     /// # Arguments
@@ -273,14 +399,155 @@ where
 }
 
 
+/// A homomorphism whose mapping is a boxed closure, e.g. a lookup table built at runtime.
+pub type BoxedHomomorphism<G, H> = Homomorphism<G, H, Box<dyn Fn(&G) -> H>>;
 
+impl<G, H> Homomorphism<G, H, Box<dyn Fn(&G) -> H>>
+where
+    G: GroupElement + 'static,
+    H: GroupElement + 'static,
+{
+    /// Creates a homomorphism from an explicit table `g ↦ images[g]`.
+    ///
+    /// every element of `source` must have an image, otherwise `HomomorphismError::MissingImage` is returned,
+    /// and the homomorphism property `f(a * b) = f(a) * f(b)` is verified for all pairs,
+    /// returning `HomomorphismError::PropertyNotHeld` if it fails.
+    pub fn from_table(source: &FiniteGroup<G>, images: HashMap<G, H>) -> Result<BoxedHomomorphism<G, H>, AbsaglError> {
+        if source.elements().iter().any(|g| !images.contains_key(g)) {
+            log::error!("The table does not cover every element of the source group");
+            return Err(HomomorphismError::MissingImage)?;
+        }
+        for a in source.elements() {
+            for b in source.elements() {
+                let f_of_op = images.get(&a.op(b));
+                let op_of_f = images[a].op(&images[b]);
+                if f_of_op != Some(&op_of_f) {
+                    log::error!("The table does not satisfy the homomorphism property");
+                    return Err(HomomorphismError::PropertyNotHeld)?;
+                }
+            }
+        }
+        let mapping: Box<dyn Fn(&G) -> H> = Box::new(move |g: &G| images[g].clone());
+        Ok(Homomorphism::new(mapping, None))
+    }
 
+    /// Finds every homomorphism `source -> target`, only meant for small groups.
+    ///
+    /// A homomorphism is determined by the images of a generating set of `source`,
+    /// and the image of a generator of order k must have an order dividing k.
+    /// every such assignment is tried, and kept if it extends consistently to the whole group,
+    /// which is exactly the homomorphism property `f(a * b) = f(a) * f(b)`.
+    /// each returned homomorphism is backed by a lookup table of the whole source group.
+    pub fn enumerate(source: &FiniteGroup<G>, target: &FiniteGroup<H>) -> Vec<BoxedHomomorphism<G, H>> {
+        if source.order() == 0 || target.order() == 0 {
+            return Vec::new();
+        }
+        let orders: Vec<usize> = source.elements().iter().map(|g| source.element_order(g)).collect();
+        let generators = source.generating_set(&orders);
+        let target_orders: Vec<usize> = target.elements().iter().map(|h| target.element_order(h)).collect();
+        let candidates: Vec<Vec<&H>> = generators
+            .iter()
+            .map(|g| {
+                let k = source.element_order(g);
+                target
+                    .elements()
+                    .iter()
+                    .zip(&target_orders)
+                    .filter(|(_, o)| k.is_multiple_of(**o))
+                    .map(|(h, _)| h)
+                    .collect()
+            })
+            .collect();
+
+        let identity = source.identity();
+        let target_identity = target.identity();
+        let mut homomorphisms = Vec::new();
+        // odometer over the candidate images, the identity of target is always a candidate so none is empty
+        let mut choice = vec![0; generators.len()];
+        loop {
+            let images: Vec<H> = choice.iter().zip(&candidates).map(|(&i, c)| c[i].clone()).collect();
+            if let Some(table) = extend_to_homomorphism(&generators, &images, &identity, &target_identity) {
+                let mapping: Box<dyn Fn(&G) -> H> = Box::new(move |g: &G| table[g].clone());
+                homomorphisms.push(Homomorphism::new(mapping, None));
+            }
+
+            let mut k = 0;
+            loop {
+                if k == choice.len() {
+                    return homomorphisms;
+                }
+                choice[k] += 1;
+                if choice[k] < candidates[k].len() {
+                    break;
+                }
+                choice[k] = 0;
+                k += 1;
+            }
+        }
+    }
+}
+
+/// Extends the generator images to a map on the whole group generated by `generators`,
+/// using BFS over words in the generators and defining `f(x * g) = f(x) * f(g)`.
+/// returns `None` if the definition is inconsistent, i.e. the images don't define a homomorphism.
+pub(crate) fn extend_to_homomorphism<G: GroupElement, H: GroupElement>(
+    generators: &[G],
+    images: &[H],
+    identity: &G,
+    target_identity: &H,
+) -> Option<HashMap<G, H>> {
+    let mut mapping: HashMap<G, H> = HashMap::new();
+    mapping.insert(identity.clone(), target_identity.clone());
+    let mut queue = vec![identity.clone()];
+
+    while let Some(x) = queue.pop() {
+        let fx = mapping[&x].clone();
+        for (g, fg) in generators.iter().zip(images) {
+            let y = x.op(g);
+            let fy = fx.op(fg);
+            match mapping.get(&y) {
+                Some(existing) if *existing != fy => return None,
+                Some(_) => {}
+                None => {
+                    mapping.insert(y.clone(), fy);
+                    queue.push(y);
+                }
+            }
+        }
+    }
+    Some(mapping)
+}
+
+/// Composes two homomorphisms `f: A -> B` and `g: B -> C` into `g ∘ f: A -> C`, which applies `f` then `g`.
+/// the description is `"g ∘ f"` built from the two descriptions, a missing one is shown as `<closure>`,
+/// and it is `None` only if both are `None`.
+pub fn compose<A, B, C, F1, F2>(
+    g: Homomorphism<B, C, F2>,
+    f: Homomorphism<A, B, F1>,
+) -> Homomorphism<A, C, impl Fn(&A) -> C>
+where
+    A: GroupElement,
+    B: GroupElement,
+    C: GroupElement,
+    F1: Fn(&A) -> B,
+    F2: Fn(&B) -> C,
+{
+    let description = match (&g.description, &f.description) {
+        (None, None) => None,
+        (g_desc, f_desc) => Some(format!(
+            "{} ∘ {}",
+            g_desc.as_deref().unwrap_or("<closure>"),
+            f_desc.as_deref().unwrap_or("<closure>"),
+        )),
+    };
+    Homomorphism::new(move |x: &A| g.apply(&f.apply(x)), description)
+}
 
-#[cfg(test)]
 
+#[cfg(test)]
 mod test_homomorphism {
     use super::*;
-    use crate::groups::{modulo::Modulo, Additive, GroupGenerators, Multiplicative};
+    use crate::groups::{modulo::Modulo, permutation::Permutation, Additive, GroupGenerators, Multiplicative};
 
     #[test]
     fn test_homomorphism_apply_modulo() {
@@ -335,6 +602,139 @@ mod test_homomorphism {
         }
     }
 
+    #[test]
+    fn test_homomorphism_sign() {
+        // sign: S4 -> {±1}, where {±1} is represented by (Z/3Z)* = {1, 2}
+        let s4 = GroupGenerators::generate_permutation_group(4).unwrap();
+        let sign_mapping = |p: &Permutation| {
+            let value = if p.sign() == 1 { 1 } else { 2 };
+            Modulo::<Multiplicative>::try_new(value, 3).unwrap()
+        };
+        let hom = Homomorphism::try_new(&s4, sign_mapping, Some("sign".to_string()))
+            .expect("sign should be a homomorphism");
+
+        let identity_h = Modulo::<Multiplicative>::try_new(1, 3).unwrap();
+        let kernel = hom.kernel(&s4, &identity_h).unwrap();
+        assert_eq!(kernel, GroupGenerators::generate_alternating_group(4).unwrap());
+    }
+
+    #[test]
+    fn test_homomorphism_compose() {
+        // Z_12 -> Z_6 -> Z_2, reducing twice is the same as reducing mod 2
+        let z12 = GroupGenerators::generate_modulo_group_add(12).unwrap();
+        let f = Homomorphism::new(|m: &Modulo<Additive>| Modulo::<Additive>::new(m.value() % 6, 6), Some("mod 6".to_string()));
+        let g = Homomorphism::new(|m: &Modulo<Additive>| Modulo::<Additive>::new(m.value() % 2, 2), Some("mod 2".to_string()));
+        let h = compose(g, f);
+
+        for x in z12.elements() {
+            assert_eq!(h.apply(x).value(), x.value() % 2);
+        }
+        assert_eq!(h.kernel(&z12, &Modulo::new(0, 2)).unwrap().order(), 6);
+        assert_eq!(format!("{:?}", h), "Homomorphism { mapping: \"mod 2 ∘ mod 6\" }");
+
+        let unnamed = Homomorphism::new(|m: &Modulo<Additive>| *m, None);
+        let h = compose(unnamed, Homomorphism::new(|m: &Modulo<Additive>| *m, None));
+        assert_eq!(format!("{:?}", h), "Homomorphism { mapping: \"<closure>\" }");
+    }
+
+    #[test]
+    fn test_homomorphism_enumerate() {
+        // |Hom(Z_m, Z_n)| = gcd(m, n)
+        let z4 = GroupGenerators::generate_modulo_group_add(4).unwrap();
+        let z6 = GroupGenerators::generate_modulo_group_add(6).unwrap();
+        assert_eq!(Homomorphism::enumerate(&z4, &z6).len(), 2);
+        assert_eq!(Homomorphism::enumerate(&z6, &z6).len(), 6);
+
+        // S_3 -> S_3: 6 automorphisms, 3 maps onto a subgroup of order 2, and the trivial map
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let homs = Homomorphism::enumerate(&s3, &s3);
+        assert_eq!(homs.len(), 10);
+        for hom in &homs {
+            for a in s3.elements() {
+                for b in s3.elements() {
+                    assert_eq!(hom.apply(&a.op(b)), hom.apply(a).op(&hom.apply(b)));
+                }
+            }
+        }
+        assert_eq!(homs.iter().filter(|h| h.is_automorphism(&s3).unwrap()).count(), 6);
+    }
+
+    #[test]
+    fn test_homomorphism_from_table() {
+        // Z_4 -> Z_2, 0, 2 ↦ 0 and 1, 3 ↦ 1
+        let z4 = GroupGenerators::generate_modulo_group_add(4).unwrap();
+        let table: HashMap<_, _> = z4.elements().iter().map(|x| (*x, Modulo::<Additive>::new(x.value() % 2, 2))).collect();
+        let hom = Homomorphism::from_table(&z4, table).unwrap();
+        assert_eq!(hom.apply(&Modulo::new(3, 4)).value(), 1);
+        assert_eq!(hom.kernel(&z4, &Modulo::new(0, 2)).unwrap().order(), 2);
+
+        // 1 ↦ 1 but 2 ↦ 1 breaks f(1 + 1) = f(1) + f(1)
+        let table: HashMap<_, _> = z4.elements().iter().map(|x| (*x, Modulo::<Additive>::new((x.value() != 0) as u64, 2))).collect();
+        match Homomorphism::from_table(&z4, table) {
+            Err(AbsaglError::Homomorphism(HomomorphismError::PropertyNotHeld)) => (),
+            result => panic!("Expected PropertyNotHeld, but got {:?}", result),
+        }
+
+        let partial = HashMap::from([(Modulo::<Additive>::new(0, 4), Modulo::<Additive>::new(0, 2))]);
+        match Homomorphism::from_table(&z4, partial) {
+            Err(AbsaglError::Homomorphism(HomomorphismError::MissingImage)) => (),
+            result => panic!("Expected MissingImage, but got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_homomorphism_identity_and_invert() {
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let id = Homomorphism::<Permutation, Permutation, _>::identity();
+        assert!(id.is_automorphism(&s3).unwrap());
+        assert_eq!(format!("{:?}", id), "Homomorphism { mapping: \"identity\" }");
+
+        // x ↦ 3x is an automorphism of Z_5, its inverse is x ↦ 2x
+        let z5 = GroupGenerators::generate_modulo_group_add(5).unwrap();
+        let triple = Homomorphism::new(|x: &Modulo<Additive>| Modulo::<Additive>::new((3 * x.value()) % 5, 5), Some("x ↦ 3x".to_string()));
+        let inverse = triple.invert(&z5, &z5).unwrap();
+        for x in z5.elements() {
+            assert_eq!(inverse.apply(&triple.apply(x)), *x);
+            assert_eq!(inverse.apply(x).value(), (2 * x.value()) % 5);
+        }
+        assert!(inverse.is_automorphism(&z5).unwrap());
+
+        let z2 = GroupGenerators::generate_modulo_group_add(2).unwrap();
+        let z4 = GroupGenerators::generate_modulo_group_add(4).unwrap();
+        let reduce = Homomorphism::new(|x: &Modulo<Additive>| Modulo::<Additive>::new(x.value() % 2, 2), None);
+        match reduce.invert(&z4, &z2) {
+            Err(AbsaglError::Homomorphism(HomomorphismError::NotBijective)) => (),
+            result => panic!("Expected NotBijective, but got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_homomorphism_factor_through() {
+        use crate::groups::factor::CosetSide;
+
+        // sign: S4 -> {±1}, S4 / A4 ≅ {±1}
+        let s4 = GroupGenerators::generate_permutation_group(4).unwrap();
+        let sign = Homomorphism::new(|p: &Permutation| {
+            let value = if p.sign() == 1 { 1 } else { 2 };
+            Modulo::<Multiplicative>::try_new(value, 3).unwrap()
+        }, None);
+        let first = sign.factor_through(&s4, &Modulo::new(1, 3)).unwrap();
+        assert_eq!(first.kernel, GroupGenerators::generate_alternating_group(4).unwrap());
+        assert_eq!(first.image.order(), 2);
+        assert_eq!(first.factor_group().order(), first.image.order());
+
+        let transposition = Permutation::from_cycles(&[vec![0, 1]], 4).unwrap();
+        let coset = Coset::new(transposition, &first.kernel, CosetSide::Left).unwrap();
+        assert_eq!(first.induced(&coset).value(), 2);
+
+        // Z_12 -> Z_4, Z_12 / <4> ≅ Z_4
+        let z12 = GroupGenerators::generate_modulo_group_add(12).unwrap();
+        let reduce = Homomorphism::new(|x: &Modulo<Additive>| Modulo::<Additive>::new(x.value() % 4, 4), None);
+        let first = reduce.factor_through(&z12, &Modulo::new(0, 4)).unwrap();
+        assert_eq!(first.kernel.order(), 3);
+        assert_eq!(first.factor_group().coset_partition().unwrap().len(), 4);
+    }
+
     #[test]
     fn test_homomorphism_kernel() {
         let valid_mapping = |m: &Modulo<Additive>| Modulo::<Additive>::try_new(m.value() % 2, 2).unwrap();
@@ -348,6 +748,56 @@ mod test_homomorphism {
         assert_eq!(kernel.order(), 3, "Kernel should have order 3");
     }
 
+    #[test]
+    fn test_homomorphism_cokernel() {
+        // Z_4 -> Z_12, x ↦ 3x has image <3>, so the cokernel is Z_12 / <3> ≅ Z_3
+        let z4 = GroupGenerators::generate_modulo_group_add(4).unwrap();
+        let z12 = GroupGenerators::generate_modulo_group_add(12).unwrap();
+        let triple = Homomorphism::new(|x: &Modulo<Additive>| Modulo::<Additive>::new(3 * x.value(), 12), None);
+        let cokernel = triple.cokernel(&z4, &z12).unwrap();
+        assert_eq!(cokernel.normal_subgroup().order(), 4);
+        assert_eq!(cokernel.factor_group().coset_partition().unwrap().len(), 3);
+        assert!(cokernel.factor_group().is_abelian());
+
+        // a surjection has a trivial cokernel
+        let reduce = Homomorphism::new(|x: &Modulo<Additive>| Modulo::<Additive>::new(x.value() % 4, 4), None);
+        assert_eq!(reduce.cokernel(&z12, &z4).unwrap().factor_group().coset_partition().unwrap().len(), 1);
+
+        // the image <(0 1)> of Z_2 -> S_3 is not normal
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let z2 = GroupGenerators::generate_modulo_group_add(2).unwrap();
+        let include = Homomorphism::new(|x: &Modulo<Additive>| {
+            if x.value() == 0 { Permutation::identity(3) } else { Permutation::from_cycles(&[vec![0, 1]], 3).unwrap() }
+        }, None);
+        match include.cokernel(&z2, &s3) {
+            Err(AbsaglError::Group(GroupError::NotNormalSubgroup)) => (),
+            result => panic!("Expected NotNormalSubgroup, but got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_homomorphism_kernel_not_normal() {
+        // the preimage of 0 is {e, (0 1)}, a subgroup of S3 that is not normal
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        let transposition = Permutation::from_cycles(&[vec![0, 1]], 3).unwrap();
+        let fake = Homomorphism::new(move |p: &Permutation| {
+            let value = if *p == Permutation::identity(3) || *p == transposition { 0 } else { 1 };
+            Modulo::<Additive>::new(value, 2)
+        }, None);
+        match fake.kernel(&s3, &Modulo::new(0, 2)) {
+            Err(AbsaglError::Group(GroupError::NotNormalSubgroup)) => (),
+            result => panic!("Expected NotNormalSubgroup, but got {:?}", result),
+        }
+
+        // the preimage of 0 is {0, 1}, which is not closed
+        let z4 = GroupGenerators::generate_modulo_group_add(4).unwrap();
+        let fake = Homomorphism::new(|x: &Modulo<Additive>| Modulo::<Additive>::new((x.value() >= 2) as u64, 2), None);
+        match fake.kernel(&z4, &Modulo::new(0, 2)) {
+            Err(AbsaglError::Group(GroupError::NotClosed)) => (),
+            result => panic!("Expected NotClosed, but got {:?}", result),
+        }
+    }
+
     #[test]
     fn test_homomorphism_image() {
         let valid_mapping = |m: &Modulo<Additive>| Modulo::<Additive>::try_new(m.value() % 2, 2).unwrap();
@@ -405,7 +855,7 @@ mod test_homomorphism {
         let z4_b = GroupGenerators::generate_modulo_group_add(4).unwrap();
 
         // Isomorphism: identity map
-        let identity_map = |x: &Modulo<Additive>| x.clone();
+        let identity_map = |x: &Modulo<Additive>| *x;
         let hom = Homomorphism::new(identity_map, Some("Identity".to_string()));
 
         assert!(hom.is_isomorphism(&z4_a, &z4_b).unwrap(), "Identity map should be an isomorphism");
@@ -424,11 +874,29 @@ mod test_homomorphism {
         assert!(!hom.is_isomorphism(&z4, &z2).unwrap(), "Invalid map should not be an isomorphism");
     }
 
+    #[test]
+    fn test_is_endomorphism() {
+        let z6 = GroupGenerators::generate_modulo_group_add(6).unwrap();
+        // x ↦ 2x is an endomorphism of Z_6 but not an automorphism
+        let double = Homomorphism::new(|x: &Modulo<Additive>| Modulo::<Additive>::new(2 * x.value() % 6, 6), None);
+        assert!(double.is_endomorphism(&z6));
+        assert!(!double.is_automorphism(&z6).unwrap());
+
+        // x ↦ x + 1 is not a homomorphism, and x ↦ x mod 3 leaves the group
+        let shift = Homomorphism::new(|x: &Modulo<Additive>| Modulo::<Additive>::new((x.value() + 1) % 6, 6), None);
+        assert!(!shift.is_endomorphism(&z6));
+        let reduce = Homomorphism::new(|x: &Modulo<Additive>| Modulo::<Additive>::new(x.value() % 3, 3), None);
+        assert!(!reduce.is_endomorphism(&z6));
+
+        let s3 = GroupGenerators::generate_permutation_group(3).unwrap();
+        assert!(s3.inner_automorphism(&Permutation::from_cycles(&[vec![0, 1]], 3).unwrap()).is_endomorphism(&s3));
+    }
+
     #[test]
     fn test_is_automorphism_success() {
         // Identity homomorphism on Z_5 is an automorphism
         let z5 = GroupGenerators::generate_modulo_group_add(5).unwrap();
-        let identity_map = |x: &Modulo<Additive>| x.clone();
+        let identity_map = |x: &Modulo<Additive>| *x;
         let hom = Homomorphism::new(identity_map, Some("Identity".to_string()));
         assert!(hom.is_automorphism(&z5).unwrap());
     }
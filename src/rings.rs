@@ -1,17 +1,26 @@
 pub mod modulo;
+pub mod polynomial;
+pub mod field;
+pub mod quotient;
+pub mod gaussian;
+pub mod matrix;
 
 
 use std::fmt;
 use std::fmt::Debug;
 use std::error::Error;
 use std::hash::Hash;
-use crate::groups::{Additive, GroupElement, Multiplicative};
+use crate::groups::{GroupElement, CanonicalRepr};
 
 use crate::error::AbsaglError;
 use crate::groups::{FiniteGroup, Group, GroupError};
 use crate::groups::modulo::ModuloError;
 use crate::rings::modulo::ModuloElement;
+use crate::utils;
+use crate::homomorphism::HomomorphismError;
 use std::collections::HashSet;
+use std::marker::PhantomData;
+use std::sync::Arc;
 
 
 /// Defines errors specific to ring validation.
@@ -25,6 +34,14 @@ pub enum RingError {
     MultiplicationNotAssociative,
     /// The distributive property does not hold.
     DistributivityFailed,
+    /// The element has no multiplicative inverse, e.g. dividing by a polynomial whose leading coefficient is not a unit.
+    NotInvertible,
+    /// GF(p^k) was requested with p not a prime or k = 0.
+    InvalidFieldOrder,
+    /// The subset is not a two-sided ideal of the ring.
+    NotAnIdeal,
+    /// A matrix was requested with dimension zero or with rows that don't form a square of the right size.
+    InvalidMatrixShape,
     /// An error occurred in the underlying group structure.
     GroupError(GroupError),
     /// An error propagated from an element's operation.
@@ -38,6 +55,10 @@ impl fmt::Display for RingError {
             RingError::MultiplicationNotClosed => write!(f, "Multiplication is not closed"),
             RingError::MultiplicationNotAssociative => write!(f, "Multiplication is not associative"),
             RingError::DistributivityFailed => write!(f, "The distributive property does not hold"),
+            RingError::NotInvertible => write!(f, "The element has no multiplicative inverse"),
+            RingError::InvalidFieldOrder => write!(f, "A finite field GF(p^k) needs a prime p and k >= 1"),
+            RingError::NotAnIdeal => write!(f, "The subset is not a two-sided ideal"),
+            RingError::InvalidMatrixShape => write!(f, "The matrix is not square of the expected dimension"),
             RingError::GroupError(e) => write!(f, "Group error: {}", e),
             RingError::ElementError(e) => write!(f, "Ring element operation error: {}", e),
         }
@@ -68,6 +89,12 @@ pub trait RingElement: GroupElement + Clone + Debug + Eq + Hash {
 
 }
 
+/// An optional trait for ring elements that can compute their own multiplicative inverse.
+pub trait MulInverse: RingElement {
+    /// Returns the multiplicative inverse, or `None` if the element is not a unit.
+    fn mul_inverse(&self) -> Option<Self>;
+}
+
 /// An abstract trait representing a Ring.
 pub trait Ring<T: RingElement> {
     /// Returns the additive identity (zero) of the ring.
@@ -81,6 +108,12 @@ pub trait Ring<T: RingElement> {
     fn is_commutative(&self) -> bool;
 }
 
+/// A trait representing a Field, a commutative ring with one where every nonzero element has a multiplicative inverse.
+pub trait Field<T: RingElement>: Ring<T> {
+    /// Returns the multiplicative inverse of `a`, or `None` if `a` is zero.
+    fn inverse(&self, a: &T) -> Option<T>;
+}
+
 /// A trait for checked operations on ring elements.
 /// This trait extends the `RingElement` trait to include checked operations that ensure
 /// the validity of the operation (e.g., checking for zero divisors).
@@ -185,6 +218,145 @@ impl<T: RingElement> FiniteRing<T> {
         self.elements.len()
     }
 
+    /// Searches the element set for the multiplicative inverse of `a`, i.e. `b` with `a * b == b * a == one`.
+    fn find_inverse(&self, a: &T) -> Option<T> {
+        let one = self.one.as_ref()?;
+        self.elements.iter().find(|b| a.mul(b) == *one && b.mul(a) == *one).cloned()
+    }
+
+    /// Checks if the ring is a field: it is commutative, has a one different from zero,
+    /// and every nonzero element has a multiplicative inverse within the element set.
+    pub fn is_field(&self) -> bool {
+        match &self.one {
+            Some(one) if *one != self.zero => {}
+            _ => return false,
+        }
+        self.is_commutative()
+            && self.elements.iter().filter(|a| **a != self.zero).all(|a| self.find_inverse(a).is_some())
+    }
+
+    /// Checks if the ring is an integral domain: it is commutative, has a one different from zero,
+    /// and has no zero divisors.
+    pub fn is_integral_domain(&self) -> bool {
+        match &self.one {
+            Some(one) if *one != self.zero => {}
+            _ => return false,
+        }
+        self.is_commutative() && self.zero_divisors().is_empty()
+    }
+
+    /// Returns the characteristic, the smallest `n > 0` with `n * one == zero`,
+    /// found by repeatedly adding one to itself. returns 0 if the ring has no one
+    /// or no such `n` exists within the order of the ring.
+    pub fn characteristic(&self) -> u64 {
+        let one = match &self.one {
+            Some(one) => one,
+            None => return 0,
+        };
+        let mut sum = one.clone();
+        for n in 1..=self.order() as u64 {
+            if sum == self.zero {
+                return n;
+            }
+            sum = sum.add(one);
+        }
+        0
+    }
+
+    /// Returns the two-sided ideal generated by `generators`, the smallest subset containing them
+    /// that is closed under addition and under left and right multiplication by every ring element.
+    /// the elements are returned in the order of the ring's element list.
+    pub fn generate_ideal(&self, generators: &[T]) -> Vec<T> {
+        let mut ideal: HashSet<T> = HashSet::from([self.zero.clone()]);
+        let mut pending: Vec<T> = generators.to_vec();
+        while let Some(x) = pending.pop() {
+            if ideal.contains(&x) {
+                continue;
+            }
+            pending.extend(ideal.iter().map(|y| x.add(y)));
+            pending.push(x.add(&x));
+            for r in &self.elements {
+                pending.push(r.mul(&x));
+                pending.push(x.mul(r));
+            }
+            ideal.insert(x);
+        }
+        self.elements.iter().filter(|a| ideal.contains(a)).cloned().collect()
+    }
+
+    /// Checks if `ideal` is a two-sided ideal: a subset containing zero, closed under addition and negation,
+    /// and absorbing left and right multiplication by every ring element.
+    pub fn is_ideal(&self, ideal: &[T]) -> bool {
+        let ring_set: HashSet<&T> = self.elements.iter().collect();
+        let ideal_set: HashSet<&T> = ideal.iter().collect();
+        ideal.iter().all(|a| ring_set.contains(a))
+            && ideal_set.contains(&self.zero)
+            && ideal.iter().all(|a| {
+                ideal_set.contains(&a.negate())
+                    && ideal.iter().all(|b| ideal_set.contains(&a.add(b)))
+                    && self.elements.iter().all(|r| ideal_set.contains(&r.mul(a)) && ideal_set.contains(&a.mul(r)))
+            })
+    }
+
+    /// Returns the Frobenius endomorphism `x ↦ x^p` if the ring is commutative and its characteristic p is a prime,
+    /// `None` otherwise. then it is a ring homomorphism, since `(a + b)^p = a^p + b^p`,
+    /// without commutativity the binomial expansion fails, e.g. in M_2(Z_2) `(A + B)^2 != A^2 + B^2` when `AB != BA`.
+    pub fn frobenius(&self) -> Option<impl Fn(&T) -> T + use<T>> {
+        if !self.is_commutative() {
+            return None;
+        }
+        let p = self.characteristic();
+        if p < 2 || utils::prime_factorization(p) != vec![(p, 1)] {
+            return None;
+        }
+        Some(move |x: &T| {
+            let mut power = x.clone();
+            for _ in 1..p {
+                power = power.mul(x);
+            }
+            power
+        })
+    }
+
+    /// Returns the idempotents, i.e. the elements with `a * a == a`.
+    pub fn idempotents(&self) -> Vec<T> {
+        self.elements.iter().filter(|a| a.mul(a) == **a).cloned().collect()
+    }
+
+    /// Returns the nilpotents, i.e. the elements with `a^k == zero` for some `1 <= k <= order`.
+    pub fn nilpotents(&self) -> Vec<T> {
+        self.elements
+            .iter()
+            .filter(|a| {
+                let mut power = (*a).clone();
+                for _ in 0..self.order() {
+                    if power == self.zero {
+                        return true;
+                    }
+                    power = power.mul(a);
+                }
+                false
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the units, i.e. the elements with a multiplicative inverse within the element set.
+    /// empty if the ring has no one.
+    pub fn units(&self) -> Vec<T> {
+        self.elements.iter().filter(|a| self.find_inverse(a).is_some()).cloned().collect()
+    }
+
+    /// Returns the zero divisors, i.e. the nonzero `a` with some nonzero `b` such that `a * b == zero`.
+    pub fn zero_divisors(&self) -> Vec<T> {
+        let nonzero: Vec<&T> = self.elements.iter().filter(|a| **a != self.zero).collect();
+        nonzero
+            .iter()
+            .filter(|a| nonzero.iter().any(|b| a.mul(b) == self.zero))
+            .map(|a| (*a).clone())
+            .collect()
+    }
+
 
     /// Checks if the `zero` element is the correct additive identity for the ring.
     pub fn is_additive_identity_valid(&self) -> bool {
@@ -242,7 +414,7 @@ impl<T: RingElement> FiniteRing<T> {
     /// Verifies only the multiplicative associativity for a given set of elements. 
     /// this is useful for testing purposes.
     /// It checks that for all elements a, b, c in the set, (a * b) * c == a * (b * c).
-    fn verify_associativity(elements: &[T]) -> Result<(), AbsaglError> {
+    pub fn verify_associativity(elements: &[T]) -> Result<(), AbsaglError> {
         for a in elements {
             for b in elements {
                 let product_ab = a.mul(b);
@@ -262,6 +434,120 @@ impl<T: RingElement> FiniteRing<T> {
 }
 
 
+impl<T: RingElement + CanonicalRepr + Send> FiniteRing<T> {
+
+    /// Forms the quotient ring R/I, whose elements are the additive cosets `a + I`.
+    /// returns `RingError::NotAnIdeal` if `ideal` is not a two-sided ideal of the ring.
+    pub fn quotient(&self, ideal: &[T]) -> Result<FiniteRing<quotient::QuotientElement<T>>, AbsaglError> {
+        if !self.is_ideal(ideal) {
+            log::error!("Cannot form a quotient ring, the subset is not a two-sided ideal");
+            return Err(RingError::NotAnIdeal)?;
+        }
+        let mut sorted = ideal.to_vec();
+        sorted.sort_by_key(|a| a.to_canonical_bytes());
+        sorted.dedup();
+        let ideal = Arc::new(sorted);
+
+        let coset = |a: &T| quotient::QuotientElement::new(a.clone(), ideal.clone());
+        let mut seen = HashSet::new();
+        let elements: Vec<_> = self.elements.iter().map(coset).filter(|c| seen.insert(c.clone())).collect();
+        let zero = coset(&self.zero);
+        // like `RingGenerators::zn(1)`, the zero ring has no one
+        let one = self.one.as_ref().map(coset).filter(|one| *one != zero);
+
+        FiniteRing::try_new_with_identities(elements, zero, one)
+    }
+}
+
+
+/// Represents a ring homomorphism `f: R -> S`, a map with `f(a + b) = f(a) + f(b)` and `f(a * b) = f(a) * f(b)`.
+/// like the group `Homomorphism`, the mapping logic is provided by a closure of type F.
+pub struct RingHomomorphism<R, S, F>
+where
+    R: RingElement,
+    S: RingElement,
+    F: Fn(&R) -> S,
+{
+    mapping: F,
+    // The description is optional, describe what the mapping is.
+    description: Option<String>,
+    _source_marker: PhantomData<R>,
+    _target_marker: PhantomData<S>,
+}
+
+impl<R, S, F> fmt::Debug for RingHomomorphism<R, S, F>
+where
+    R: RingElement,
+    S: RingElement,
+    F: Fn(&R) -> S,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mapping_display = match &self.description {
+            Some(desc) => desc.as_str(),
+            None => "<closure>",
+        };
+        f.debug_struct("RingHomomorphism")
+         .field("mapping", &mapping_display)
+         .finish()
+    }
+}
+
+impl<R, S, F> RingHomomorphism<R, S, F>
+where
+    R: RingElement,
+    S: RingElement,
+    F: Fn(&R) -> S,
+{
+    /// Creates a new ring homomorphism from a mapping function.
+    /// This is an "unchecked" constructor, it assumes the mapping preserves addition and multiplication.
+    pub fn new(mapping_fn: F, description: Option<String>) -> Self {
+        Self {
+            mapping: mapping_fn,
+            description,
+            _source_marker: PhantomData,
+            _target_marker: PhantomData,
+        }
+    }
+
+    /// Attempts to create a new ring homomorphism by verifying `f(a + b) = f(a) + f(b)`
+    /// and `f(a * b) = f(a) * f(b)` for all pairs of elements in the source ring.
+    /// returns `HomomorphismError::PropertyNotHeld` if either fails.
+    pub fn try_new(source_ring: &FiniteRing<R>, mapping_fn: F, description: Option<String>) -> Result<Self, AbsaglError> {
+        for a in &source_ring.elements {
+            for b in &source_ring.elements {
+                if mapping_fn(&a.add(b)) != mapping_fn(a).add(&mapping_fn(b)) {
+                    log::error!("The mapping does not preserve addition for {:?} and {:?}", a, b);
+                    return Err(HomomorphismError::PropertyNotHeld)?;
+                }
+                if mapping_fn(&a.mul(b)) != mapping_fn(a).mul(&mapping_fn(b)) {
+                    log::error!("The mapping does not preserve multiplication for {:?} and {:?}", a, b);
+                    return Err(HomomorphismError::PropertyNotHeld)?;
+                }
+            }
+        }
+        Ok(Self::new(mapping_fn, description))
+    }
+
+    /// Applies the homomorphism to an element of the source ring.
+    pub fn apply(&self, r: &R) -> S {
+        (self.mapping)(r)
+    }
+
+    /// Computes the kernel {r in R | f(r) = 0}, which is always a two-sided ideal of R.
+    /// the elements are returned in the order of the source ring's element list, ready for `FiniteRing::quotient`.
+    pub fn kernel(&self, source_ring: &FiniteRing<R>, zero_s: &S) -> Vec<R> {
+        source_ring.elements.iter().filter(|r| self.apply(r) == *zero_s).cloned().collect()
+    }
+
+    /// Computes the image {f(r) | r in R}, a subring of the target, with its identities discovered by `FiniteRing::try_new`.
+    pub fn image(&self, source_ring: &FiniteRing<R>) -> Result<FiniteRing<S>, AbsaglError> {
+        let mut seen = HashSet::new();
+        let elements: Vec<S> = source_ring.elements.iter().map(|r| self.apply(r)).filter(|s| seen.insert(s.clone())).collect();
+        FiniteRing::try_new(elements)
+    }
+}
+
+
 /// A collection of ring generators.
 pub struct RingGenerators;
 
@@ -280,6 +566,26 @@ impl RingGenerators {
         // Use the efficient, checked constructor
         FiniteRing::try_new_with_identities(elements, zero, one)
     }
+
+    /// Generates the finite field GF(p^k) as F_p[x] / (f), where f is a monic irreducible polynomial of degree k.
+    pub fn gf(p: u64, k: u32) -> Result<FiniteRing<field::GaloisFieldElement>, AbsaglError> {
+        let field = field::GaloisField::new(p, k)?;
+        FiniteRing::try_new_with_identities(field.elements(), field.zero().clone(), field.one().cloned())
+    }
+
+    /// Generates the ring of Gaussian integers modulo n, `Z[i]/(n)`, with all n^2 elements.
+    /// it is a field exactly when n is a prime with n = 3 mod 4.
+    pub fn gaussian_zn(n: u64) -> Result<FiniteRing<gaussian::GaussianModulo>, AbsaglError> {
+        if n == 0 {
+            log::error!("Cannot create a ring with zero modulus.");
+            return Err(AbsaglError::from(ModuloError::ZeroModulus));
+        }
+        let elements = gaussian::GaussianModulo::generate_gaussian_ring(n);
+        let zero = gaussian::GaussianModulo::new(0, 0, n);
+        let one = if n > 1 { Some(gaussian::GaussianModulo::new(1, 0, n)) } else { None };
+
+        FiniteRing::try_new_with_identities(elements, zero, one)
+    }
 }
 
 
@@ -329,6 +635,179 @@ mod tests {
         assert!(ring.is_commutative());
     }
 
+    #[test]
+    fn test_ring_is_field() {
+        assert!(RingGenerators::zn(5).unwrap().is_field());
+        assert!(RingGenerators::zn(2).unwrap().is_field());
+        assert!(!RingGenerators::zn(4).unwrap().is_field());
+        assert!(!RingGenerators::zn(12).unwrap().is_field());
+        // the zero ring has no one different from zero
+        assert!(!RingGenerators::zn(1).unwrap().is_field());
+        assert!(RingGenerators::gf(2, 2).unwrap().is_field());
+    }
+
+    #[test]
+    fn test_ring_units_and_zero_divisors() {
+        let ring = RingGenerators::zn(12).unwrap();
+        let values = |elements: Vec<modulo::ModuloElement>| {
+            let mut values: Vec<u64> = elements.iter().map(|e| e.value()).collect();
+            values.sort();
+            values
+        };
+        assert_eq!(values(ring.units()), vec![1, 5, 7, 11]);
+        assert_eq!(values(ring.zero_divisors()), vec![2, 3, 4, 6, 8, 9, 10]);
+
+        // a field has every nonzero element as a unit and no zero divisors
+        let ring = RingGenerators::zn(7).unwrap();
+        assert_eq!(ring.units().len(), 6);
+        assert!(ring.zero_divisors().is_empty());
+    }
+
+    #[test]
+    fn test_ring_integral_domain_and_characteristic() {
+        assert!(RingGenerators::zn(7).unwrap().is_integral_domain());
+        assert!(!RingGenerators::zn(6).unwrap().is_integral_domain());
+        assert!(!RingGenerators::zn(1).unwrap().is_integral_domain());
+
+        for n in [2, 6, 12] {
+            assert_eq!(RingGenerators::zn(n).unwrap().characteristic(), n);
+        }
+        let gf9 = RingGenerators::gf(3, 2).unwrap();
+        assert_eq!(gf9.characteristic(), 3);
+        assert!(gf9.is_integral_domain());
+
+        let no_one = FiniteRing::new(vec![modulo::ModuloElement::new(0, 4), modulo::ModuloElement::new(2, 4)], modulo::ModuloElement::new(0, 4), None);
+        assert_eq!(no_one.characteristic(), 0);
+    }
+
+    #[test]
+    fn test_ring_idempotents_and_nilpotents() {
+        let ring = RingGenerators::zn(12).unwrap();
+        let values = |elements: Vec<modulo::ModuloElement>| {
+            let mut values: Vec<u64> = elements.iter().map(|e| e.value()).collect();
+            values.sort();
+            values
+        };
+        // Z_12 = Z_4 x Z_3, the idempotents are the pairs of 0 and 1
+        assert_eq!(values(ring.idempotents()), vec![0, 1, 4, 9]);
+        assert_eq!(values(ring.nilpotents()), vec![0, 6]);
+
+        assert_eq!(values(RingGenerators::zn(8).unwrap().nilpotents()), vec![0, 2, 4, 6]);
+        assert_eq!(values(RingGenerators::zn(5).unwrap().nilpotents()), vec![0]);
+    }
+
+    #[test]
+    fn test_ring_generate_ideal_and_quotient() {
+        let ring = RingGenerators::zn(12).unwrap();
+        let e = |v| modulo::ModuloElement::new(v, 12);
+
+        // (8) = (4) = {0, 4, 8} in Z_12
+        let ideal = ring.generate_ideal(&[e(8)]);
+        assert_eq!(ideal, vec![e(0), e(4), e(8)]);
+        assert!(ring.is_ideal(&ideal));
+        assert!(!ring.is_ideal(&[e(0), e(4)]));
+        // (4, 6) = (2)
+        assert_eq!(ring.generate_ideal(&[e(4), e(6)]).len(), 6);
+        assert_eq!(ring.generate_ideal(&[]), vec![e(0)]);
+
+        // Z_12 / (4) = Z_4
+        let quotient = ring.quotient(&ideal).unwrap();
+        assert_eq!(quotient.order(), 4);
+        assert_eq!(quotient.characteristic(), 4);
+        assert_eq!(quotient.nilpotents().len(), 2);
+        assert!(!quotient.is_field());
+
+        // Z_12 / (3) = Z_3 is a field
+        let quotient = ring.quotient(&ring.generate_ideal(&[e(3)])).unwrap();
+        assert!(quotient.is_field());
+
+        // Z_12 / Z_12 is the zero ring
+        assert_eq!(ring.quotient(&ring.generate_ideal(&[e(1)])).unwrap().order(), 1);
+
+        match ring.quotient(&[e(0), e(4)]) {
+            Err(AbsaglError::Ring(RingError::NotAnIdeal)) => (),
+            _ => panic!("Expected NotAnIdeal error"),
+        }
+    }
+
+    #[test]
+    fn test_ring_generators_gaussian_zn() {
+        let ring = RingGenerators::gaussian_zn(3).unwrap();
+        assert_eq!(ring.order(), 9);
+        assert!(ring.is_commutative());
+        // 3 = 3 mod 4 stays prime in Z[i], so Z[i]/(3) = GF(9)
+        assert!(ring.is_field());
+        // 5 = (1 + 2i)(1 - 2i) splits, so Z[i]/(5) has zero divisors
+        assert!(!RingGenerators::gaussian_zn(5).unwrap().is_field());
+        // 2 = -i(1 + i)^2 ramifies, so 1 + i is nilpotent
+        assert_eq!(RingGenerators::gaussian_zn(2).unwrap().nilpotents().len(), 2);
+        assert!(RingGenerators::gaussian_zn(0).is_err());
+    }
+
+    #[test]
+    fn test_ring_frobenius() {
+        // x^5 = x in Z_5 by Fermat's little theorem
+        let z5 = RingGenerators::zn(5).unwrap();
+        let frobenius = z5.frobenius().unwrap();
+        for a in &z5.elements {
+            assert_eq!(frobenius(a), *a);
+        }
+        assert!(RingGenerators::zn(6).unwrap().frobenius().is_none());
+
+        // over GF(4) the Frobenius x -> x^2 is a nontrivial automorphism
+        let gf4 = RingGenerators::gf(2, 2).unwrap();
+        let frobenius = gf4.frobenius().unwrap();
+        let images: HashSet<_> = gf4.elements.iter().map(&frobenius).collect();
+        assert_eq!(images.len(), 4);
+        assert!(gf4.elements.iter().any(|a| frobenius(a) != *a));
+        for a in &gf4.elements {
+            for b in &gf4.elements {
+                assert_eq!(frobenius(&a.add(b)), frobenius(a).add(&frobenius(b)));
+                assert_eq!(frobenius(&a.mul(b)), frobenius(a).mul(&frobenius(b)));
+            }
+        }
+
+        // M_2(Z_2) has characteristic 2 but is not commutative, so x -> x^2 is not additive
+        let matrices = matrix::MatrixRing::new(2, 2).unwrap();
+        let ring = FiniteRing::try_new_with_identities(
+            matrices.elements(),
+            matrices.zero().clone(),
+            matrices.one().cloned(),
+        ).unwrap();
+        assert_eq!(ring.characteristic(), 2);
+        assert!(ring.frobenius().is_none());
+    }
+
+    #[test]
+    fn test_ring_homomorphism() {
+        // the reduction Z_12 -> Z_4
+        let z12 = RingGenerators::zn(12).unwrap();
+        let reduction = RingHomomorphism::try_new(
+            &z12,
+            |a: &ModuloElement| ModuloElement::new(a.value(), 4),
+            Some("mod 4".to_string()),
+        ).unwrap();
+        assert_eq!(reduction.apply(&ModuloElement::new(7, 12)), ModuloElement::new(3, 4));
+
+        let kernel = reduction.kernel(&z12, &ModuloElement::new(0, 4));
+        let values: Vec<u64> = kernel.iter().map(|a| a.value()).collect();
+        assert_eq!(values, vec![0, 4, 8]);
+        assert!(z12.is_ideal(&kernel));
+        // Z_12 / ker = im
+        let image = reduction.image(&z12).unwrap();
+        assert_eq!(image.order(), 4);
+        assert_eq!(z12.quotient(&kernel).unwrap().order(), image.order());
+
+        // x -> 2x preserves addition but not multiplication
+        let doubling = RingHomomorphism::try_new(&z12, |a: &ModuloElement| a.add(a), None);
+        match doubling {
+            Err(AbsaglError::Homomorphism(HomomorphismError::PropertyNotHeld)) => (),
+            _ => panic!("Expected PropertyNotHeld error"),
+        }
+        // Z_12 -> Z_5 is not even additive
+        assert!(RingHomomorphism::try_new(&z12, |a: &ModuloElement| ModuloElement::new(a.value(), 5), None).is_err());
+    }
+
     // --- Tests for Axiom Failures ---
 
         // --- A test case for a structure that is Distributive but NOT Associative ---
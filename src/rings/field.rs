@@ -0,0 +1,300 @@
+use crate::error::AbsaglError;
+use crate::groups::{GroupElement, CanonicalRepr};
+use crate::rings::{Field, Ring, RingElement, RingError, MulInverse};
+use crate::rings::modulo::ModuloElement;
+use crate::rings::polynomial::Polynomial;
+use crate::utils;
+use std::fmt;
+
+
+/// Returns all polynomials over F_p of degree less than `d`, in the order of their base-p digits.
+fn polynomials_below(p: u64, d: u32) -> impl Iterator<Item = Polynomial<ModuloElement>> {
+    (0..p.pow(d)).map(move |mut index| {
+        let mut coefficients = Vec::with_capacity(d as usize);
+        for _ in 0..d {
+            coefficients.push(ModuloElement::new(index % p, p));
+            index /= p;
+        }
+        Polynomial::new(coefficients)
+    })
+}
+
+/// Returns all monic polynomials over F_p of degree `d`.
+fn monic_polynomials(p: u64, d: u32) -> impl Iterator<Item = Polynomial<ModuloElement>> {
+    polynomials_below(p, d).map(move |f| {
+        let mut coefficients = f.coefficients().to_vec();
+        coefficients.resize(d as usize, ModuloElement::new(0, p));
+        coefficients.push(ModuloElement::new(1, p));
+        Polynomial::new(coefficients)
+    })
+}
+
+/// Checks if a polynomial over F_p is irreducible, i.e. it has degree at least 1
+/// and no monic factor of degree between 1 and deg / 2.
+/// this is a brute force check, only meant for small p and degree.
+pub fn is_irreducible(f: &Polynomial<ModuloElement>) -> bool {
+    let (degree, p) = match (f.degree(), f.leading_coefficient()) {
+        (Some(degree), Some(lead)) if degree >= 1 => (degree as u32, lead.modulus()),
+        _ => return false,
+    };
+    (1..=degree / 2).all(|d| {
+        monic_polynomials(p, d).all(|g| match f.div_rem(&g) {
+            Ok((_, remainder)) => !remainder.is_zero(),
+            Err(_) => true,
+        })
+    })
+}
+
+
+/// The finite field GF(p^k), represented as F_p[x] / (f) for a monic irreducible f of degree k.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GaloisField {
+    p: u64,
+    k: u32,
+    modulus: Polynomial<ModuloElement>,
+    zero: GaloisFieldElement,
+    one: GaloisFieldElement,
+}
+
+impl GaloisField {
+
+    /// Creates GF(p^k), using the first monic irreducible polynomial of degree k found over F_p.
+    /// returns `RingError::InvalidFieldOrder` if p is not a prime or k is zero.
+    pub fn new(p: u64, k: u32) -> Result<Self, AbsaglError> {
+        if k == 0 || utils::prime_factorization(p) != vec![(p, 1)] {
+            log::error!("GF(p^k) needs a prime p and k >= 1, got p = {}, k = {}", p, k);
+            return Err(RingError::InvalidFieldOrder)?;
+        }
+        // an irreducible polynomial of every degree exists over F_p, so this always finds one
+        let modulus = monic_polynomials(p, k)
+            .find(is_irreducible)
+            .expect("an irreducible polynomial of every degree exists over F_p");
+        let zero = GaloisFieldElement::reduce(Polynomial::zero(), &modulus);
+        let one = GaloisFieldElement::reduce(Polynomial::new(vec![ModuloElement::new(1, p)]), &modulus);
+        Ok(GaloisField { p, k, modulus, zero, one })
+    }
+
+    /// Returns the characteristic p
+    pub fn characteristic(&self) -> u64 {
+        self.p
+    }
+
+    /// Returns the degree k of the extension over F_p
+    pub fn degree(&self) -> u32 {
+        self.k
+    }
+
+    /// Returns the number of elements p^k
+    pub fn order(&self) -> u64 {
+        self.p.pow(self.k)
+    }
+
+    /// Returns the irreducible polynomial the field is built from
+    pub fn modulus(&self) -> &Polynomial<ModuloElement> {
+        &self.modulus
+    }
+
+    /// Creates the element `c_0 + c_1 x + ...` reduced modulo the irreducible polynomial.
+    pub fn element(&self, coefficients: &[u64]) -> GaloisFieldElement {
+        let value = Polynomial::new(coefficients.iter().map(|&c| ModuloElement::new(c, self.p)).collect());
+        GaloisFieldElement::reduce(value, &self.modulus)
+    }
+
+    /// Returns all p^k elements of the field, i.e. every polynomial of degree less than k.
+    pub fn elements(&self) -> Vec<GaloisFieldElement> {
+        polynomials_below(self.p, self.k)
+            .map(|value| GaloisFieldElement { value, modulus: self.modulus.clone() })
+            .collect()
+    }
+}
+
+impl Ring<GaloisFieldElement> for GaloisField {
+    fn zero(&self) -> &GaloisFieldElement {
+        &self.zero
+    }
+
+    fn one(&self) -> Option<&GaloisFieldElement> {
+        Some(&self.one)
+    }
+
+    /// multiplication of polynomials is commutative
+    fn is_commutative(&self) -> bool {
+        true
+    }
+}
+
+impl Field<GaloisFieldElement> for GaloisField {
+    fn inverse(&self, a: &GaloisFieldElement) -> Option<GaloisFieldElement> {
+        a.mul_inverse()
+    }
+}
+
+
+/// An element of GF(p^k), a polynomial of degree less than k over F_p.
+/// like `ModuloElement`, it carries the irreducible polynomial it is reduced by.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GaloisFieldElement {
+    value: Polynomial<ModuloElement>,
+    modulus: Polynomial<ModuloElement>,
+}
+
+impl GaloisFieldElement {
+
+    fn reduce(value: Polynomial<ModuloElement>, modulus: &Polynomial<ModuloElement>) -> Self {
+        // the modulus is monic, so the division never fails
+        let (_, remainder) = value.div_rem(modulus).expect("the modulus of a field is monic");
+        GaloisFieldElement { value: remainder, modulus: modulus.clone() }
+    }
+
+    /// Returns the polynomial representing the element
+    pub fn value(&self) -> &Polynomial<ModuloElement> {
+        &self.value
+    }
+
+    /// Returns the irreducible polynomial the element is reduced by
+    pub fn modulus(&self) -> &Polynomial<ModuloElement> {
+        &self.modulus
+    }
+}
+
+impl RingElement for GaloisFieldElement {
+    fn add(&self, other: &Self) -> Self {
+        assert_eq!(self.modulus, other.modulus, "Cannot add elements of different fields");
+        GaloisFieldElement { value: self.value.add(&other.value), modulus: self.modulus.clone() }
+    }
+
+    fn negate(&self) -> Self {
+        GaloisFieldElement { value: self.value.negate(), modulus: self.modulus.clone() }
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        assert_eq!(self.modulus, other.modulus, "Cannot multiply elements of different fields");
+        Self::reduce(self.value.mul(&other.value), &self.modulus)
+    }
+}
+
+impl MulInverse for GaloisFieldElement {
+    /// every nonzero element is invertible, the inverse is `a^(q - 2)` where q = p^k,
+    /// since the multiplicative group has order q - 1.
+    fn mul_inverse(&self) -> Option<Self> {
+        if self.value.is_zero() {
+            return None;
+        }
+        let lead = self.modulus.leading_coefficient()?;
+        let (p, k) = (lead.modulus(), self.modulus.degree()? as u32);
+        let mut exp = p.pow(k) - 2;
+
+        let mut res = Self::reduce(Polynomial::new(vec![ModuloElement::new(1, p)]), &self.modulus);
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp % 2 == 1 {
+                res = res.mul(&base);
+            }
+            base = base.mul(&base);
+            exp /= 2;
+        }
+        Some(res)
+    }
+}
+
+// Implementation for the primary GROUP properties (always additive for rings)
+impl GroupElement for GaloisFieldElement {
+    fn op(&self, other: &Self) -> Self {
+        self.add(other)
+    }
+
+    fn inverse(&self) -> Self {
+        self.negate()
+    }
+}
+
+impl CanonicalRepr for GaloisFieldElement {
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        [self.value.to_canonical_bytes(), self.modulus.to_canonical_bytes()].concat()
+    }
+}
+
+impl fmt::Display for GaloisFieldElement {
+    /// Prints the polynomial from the highest power down, e.g. `2x^2 + x + 1`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms: Vec<String> = self.value
+            .coefficients()
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| c.value() != 0)
+            .map(|(i, c)| {
+                let coefficient = if c.value() == 1 && i > 0 { String::new() } else { c.value().to_string() };
+                match i {
+                    0 => coefficient,
+                    1 => format!("{}x", coefficient),
+                    _ => format!("{}x^{}", coefficient, i),
+                }
+            })
+            .collect();
+        if terms.is_empty() {
+            write!(f, "0")
+        } else {
+            write!(f, "{}", terms.join(" + "))
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rings::RingGenerators;
+
+    #[test]
+    fn test_is_irreducible() {
+        let poly = |c: &[u64], p: u64| Polynomial::new(c.iter().map(|&x| ModuloElement::new(x, p)).collect());
+        // x^2 + x + 1 is irreducible over F_2, x^2 + 1 = (x + 1)^2 is not
+        assert!(is_irreducible(&poly(&[1, 1, 1], 2)));
+        assert!(!is_irreducible(&poly(&[1, 0, 1], 2)));
+        // x^2 + 1 is irreducible over F_3
+        assert!(is_irreducible(&poly(&[1, 0, 1], 3)));
+        assert!(!is_irreducible(&poly(&[1], 3)));
+    }
+
+    #[test]
+    fn test_galois_field_creation() {
+        let gf4 = GaloisField::new(2, 2).unwrap();
+        assert_eq!(gf4.order(), 4);
+        assert_eq!(gf4.elements().len(), 4);
+        assert_eq!(gf4.modulus().degree(), Some(2));
+
+        assert!(GaloisField::new(4, 2).is_err());
+        assert!(GaloisField::new(3, 0).is_err());
+    }
+
+    #[test]
+    fn test_galois_field_arithmetic() {
+        // GF(4) = F_2[x] / (x^2 + x + 1), so x^2 = x + 1
+        let gf4 = GaloisField::new(2, 2).unwrap();
+        let x = gf4.element(&[0, 1]);
+        assert_eq!(x.mul(&x), gf4.element(&[1, 1]));
+        assert_eq!(format!("{}", x.mul(&x)), "x + 1");
+        assert_eq!(x.add(&x), *gf4.zero());
+
+        for field in [gf4, GaloisField::new(2, 3).unwrap(), GaloisField::new(3, 2).unwrap()] {
+            for a in field.elements() {
+                match field.inverse(&a) {
+                    Some(inv) => assert_eq!(a.mul(&inv), *field.one().unwrap()),
+                    None => assert_eq!(a, *field.zero()),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ring_generators_gf() {
+        for (p, k) in [(2, 2), (2, 3), (3, 2)] {
+            let ring = RingGenerators::gf(p, k).unwrap();
+            assert_eq!(ring.order() as u64, p.pow(k));
+            assert!(ring.is_commutative());
+            assert!(ring.one().is_some());
+        }
+        assert!(RingGenerators::gf(6, 1).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use crate::error::AbsaglError;
+use crate::groups::{GroupElement, CanonicalRepr};
+use crate::groups::modulo::ModuloError;
+use crate::rings::RingElement;
+use std::fmt;
+
+
+/// Represents an element `re + im i` of the Gaussian integers modulo n, `Z[i]/(n)`.
+/// both parts are reduced modulo the modulus, and `i^2 = -1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GaussianModulo {
+    re: u64,
+    im: u64,
+    modulus: u64,
+}
+
+impl GaussianModulo {
+    /// Creates a new `GaussianModulo`, reducing both parts modulo the modulus.
+    /// this is a unchecked constructor.
+    pub fn new(re: u64, im: u64, modulus: u64) -> Self {
+        Self { re: re % modulus, im: im % modulus, modulus }
+    }
+
+    /// Attempts to create a new `GaussianModulo`.
+    /// Returns an error if the modulus is zero.
+    pub fn try_new(re: u64, im: u64, modulus: u64) -> Result<Self, AbsaglError> {
+        if modulus == 0 {
+            log::error!("Modulus cannot be zero");
+            return Err(ModuloError::ZeroModulus)?;
+        }
+        Ok(Self::new(re, im, modulus))
+    }
+
+    pub fn re(&self) -> u64 {
+        self.re
+    }
+
+    pub fn im(&self) -> u64 {
+        self.im
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Generates all n^2 elements of `Z[i]/(n)`, ordered by real part then imaginary part.
+    /// Returns an empty vector if the modulus is zero.
+    pub fn generate_gaussian_ring(modulus: u64) -> Vec<Self> {
+        if modulus == 0 {
+            log::error!("Cannot generate gaussian ring with zero modulus");
+            return vec![];
+        }
+        (0..modulus)
+            .flat_map(|re| (0..modulus).map(move |im| Self::new(re, im, modulus)))
+            .collect()
+    }
+}
+
+// Implementation for the RING properties
+impl RingElement for GaussianModulo {
+    fn add(&self, other: &Self) -> Self {
+        if self.modulus != other.modulus {
+            panic!("Cannot add elements with different moduli");
+        }
+        Self::new(self.re + other.re, self.im + other.im, self.modulus)
+    }
+
+    fn negate(&self) -> Self {
+        Self::new(self.modulus - self.re, self.modulus - self.im, self.modulus)
+    }
+
+    /// (a + bi)(c + di) = (ac - bd) + (ad + bc)i
+    fn mul(&self, other: &Self) -> Self {
+        if self.modulus != other.modulus {
+            panic!("Cannot multiply elements with different moduli");
+        }
+        // use u128 intermediates so moduli above 2^32 don't overflow
+        let n = self.modulus as u128;
+        let (a, b, c, d) = (self.re as u128, self.im as u128, other.re as u128, other.im as u128);
+        let re = (a * c % n + n - b * d % n) % n;
+        let im = (a * d % n + b * c % n) % n;
+        Self { re: re as u64, im: im as u64, modulus: self.modulus }
+    }
+}
+
+// Implementation for the primary GROUP properties (always additive for rings)
+impl GroupElement for GaussianModulo {
+    fn op(&self, other: &Self) -> Self {
+        self.add(other)
+    }
+
+    fn inverse(&self) -> Self {
+        self.negate()
+    }
+}
+
+impl CanonicalRepr for GaussianModulo {
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        [self.re.to_be_bytes(), self.im.to_be_bytes(), self.modulus.to_be_bytes()].concat()
+    }
+}
+
+impl fmt::Display for GaussianModulo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}i (mod {})", self.re, self.im, self.modulus)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gaussian_modulo_creation() {
+        let elem = GaussianModulo::new(7, 12, 5);
+        assert_eq!((elem.re(), elem.im(), elem.modulus()), (2, 2, 5));
+        assert!(GaussianModulo::try_new(1, 1, 0).is_err());
+        assert_eq!(GaussianModulo::generate_gaussian_ring(3).len(), 9);
+    }
+
+    #[test]
+    fn test_gaussian_modulo_arithmetic() {
+        let i = GaussianModulo::new(0, 1, 7);
+        assert_eq!(i.mul(&i), GaussianModulo::new(6, 0, 7));
+        // (2 + 3i)(4 + 5i) = -7 + 22i = 0 + 1i mod 7
+        assert_eq!(GaussianModulo::new(2, 3, 7).mul(&GaussianModulo::new(4, 5, 7)), GaussianModulo::new(0, 1, 7));
+        assert_eq!(GaussianModulo::new(2, 3, 7).add(&GaussianModulo::new(5, 5, 7)), GaussianModulo::new(0, 1, 7));
+        assert_eq!(GaussianModulo::new(2, 0, 7).negate(), GaussianModulo::new(5, 0, 7));
+        // (1 + 2i)(1 - 2i) = 5 = 0 mod 5
+        assert_eq!(GaussianModulo::new(1, 2, 5).mul(&GaussianModulo::new(1, 3, 5)), GaussianModulo::new(0, 0, 5));
+    }
+
+    #[test]
+    fn test_gaussian_modulo_display() {
+        assert_eq!(format!("{}", GaussianModulo::new(3, 4, 5)), "3 + 4i (mod 5)");
+    }
+}
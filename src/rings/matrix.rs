@@ -0,0 +1,265 @@
+use crate::error::AbsaglError;
+use crate::groups::{GroupElement, CanonicalRepr};
+use crate::groups::modulo::ModuloError;
+use crate::rings::{Ring, RingElement, RingError};
+use crate::rings::modulo::ModuloElement;
+use crate::utils;
+use std::fmt;
+
+
+/// The ring M_n(Z_m) of n x n matrices over Z_m.
+/// it holds the shape and the identities, the elements themselves are `MatrixElement`s,
+/// enumerating them all with `elements()` is only feasible for tiny n and m since there are m^(n^2).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatrixRing {
+    dim: usize,
+    modulus: u64,
+    zero: MatrixElement,
+    one: MatrixElement,
+}
+
+impl MatrixRing {
+
+    /// Creates M_dim(Z_modulus), returns `RingError::InvalidMatrixShape` if dim is zero
+    /// or `ModuloError::ZeroModulus` if the modulus is zero.
+    pub fn new(dim: usize, modulus: u64) -> Result<Self, AbsaglError> {
+        if dim == 0 {
+            log::error!("A matrix ring needs a dimension of at least 1");
+            return Err(RingError::InvalidMatrixShape)?;
+        }
+        if modulus == 0 {
+            log::error!("Modulus cannot be zero");
+            return Err(ModuloError::ZeroModulus)?;
+        }
+        let zero = MatrixElement { entries: vec![ModuloElement::new(0, modulus); dim * dim], dim };
+        let one = MatrixElement {
+            entries: (0..dim * dim).map(|k| ModuloElement::new((k / dim == k % dim) as u64, modulus)).collect(),
+            dim,
+        };
+        Ok(MatrixRing { dim, modulus, zero, one })
+    }
+
+    /// Returns the size n of the n x n matrices
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Returns the modulus m of the entries
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Creates a matrix from its rows, reducing each entry modulo m.
+    /// returns `RingError::InvalidMatrixShape` if the rows don't form a dim x dim matrix.
+    pub fn element(&self, rows: &[Vec<u64>]) -> Result<MatrixElement, AbsaglError> {
+        if rows.len() != self.dim || rows.iter().any(|row| row.len() != self.dim) {
+            log::error!("Expected a {0} x {0} matrix", self.dim);
+            return Err(RingError::InvalidMatrixShape)?;
+        }
+        let entries = rows.iter().flatten().map(|&v| ModuloElement::new(v, self.modulus)).collect();
+        Ok(MatrixElement { entries, dim: self.dim })
+    }
+
+    /// Returns all m^(n^2) matrices, ordered by their entries read row by row as base-m digits.
+    pub fn elements(&self) -> Vec<MatrixElement> {
+        let size = self.dim * self.dim;
+        let count = self.modulus.pow(size as u32);
+        (0..count)
+            .map(|mut index| {
+                let mut entries = vec![ModuloElement::new(0, self.modulus); size];
+                for entry in entries.iter_mut().rev() {
+                    *entry = ModuloElement::new(index % self.modulus, self.modulus);
+                    index /= self.modulus;
+                }
+                MatrixElement { entries, dim: self.dim }
+            })
+            .collect()
+    }
+}
+
+impl Ring<MatrixElement> for MatrixRing {
+    fn zero(&self) -> &MatrixElement {
+        &self.zero
+    }
+
+    fn one(&self) -> Option<&MatrixElement> {
+        Some(&self.one)
+    }
+
+    /// M_n(Z_m) is commutative only for n = 1, or m = 1 where every matrix is zero.
+    fn is_commutative(&self) -> bool {
+        self.dim == 1 || self.modulus == 1
+    }
+}
+
+
+/// A square matrix over Z_m, its entries are stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MatrixElement {
+    entries: Vec<ModuloElement>,
+    dim: usize,
+}
+
+impl MatrixElement {
+
+    /// Returns the size n of the n x n matrix
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Returns the entry in row `i` and column `j`
+    pub fn get(&self, i: usize, j: usize) -> ModuloElement {
+        self.entries[i * self.dim + j]
+    }
+
+    fn modulus(&self) -> u64 {
+        self.entries[0].modulus()
+    }
+
+    /// Computes the determinant by Laplace expansion along the first row.
+    /// it only uses ring operations, so it works for any modulus, but it is O(n!) and meant for small matrices.
+    pub fn determinant(&self) -> ModuloElement {
+        fn minor_determinant(m: &MatrixElement, rows: &[usize], columns: &[usize]) -> ModuloElement {
+            if rows.len() == 1 {
+                return m.get(rows[0], columns[0]);
+            }
+            let mut det = ModuloElement::new(0, m.modulus());
+            for (k, &column) in columns.iter().enumerate() {
+                let remaining: Vec<usize> = columns.iter().copied().filter(|&c| c != column).collect();
+                let term = m.get(rows[0], column).mul(&minor_determinant(m, &rows[1..], &remaining));
+                det = if k % 2 == 0 { det.add(&term) } else { det.add(&term.negate()) };
+            }
+            det
+        }
+        let indices: Vec<usize> = (0..self.dim).collect();
+        minor_determinant(self, &indices, &indices)
+    }
+
+    /// Checks if the matrix is a unit of M_n(Z_m), which holds iff its determinant is a unit of Z_m.
+    pub fn is_invertible(&self) -> bool {
+        utils::gcd_u64(self.determinant().value(), self.modulus()) == 1
+    }
+}
+
+impl RingElement for MatrixElement {
+    fn add(&self, other: &Self) -> Self {
+        assert_eq!(self.dim, other.dim, "Cannot add matrices of different sizes");
+        let entries = self.entries.iter().zip(&other.entries).map(|(a, b)| a.add(b)).collect();
+        MatrixElement { entries, dim: self.dim }
+    }
+
+    fn negate(&self) -> Self {
+        MatrixElement { entries: self.entries.iter().map(|a| a.negate()).collect(), dim: self.dim }
+    }
+
+    /// The usual matrix product, `(AB)_ij = sum_k A_ik B_kj`.
+    fn mul(&self, other: &Self) -> Self {
+        assert_eq!(self.dim, other.dim, "Cannot multiply matrices of different sizes");
+        let n = self.dim;
+        let entries = (0..n * n)
+            .map(|index| {
+                let (i, j) = (index / n, index % n);
+                (0..n).fold(ModuloElement::new(0, self.modulus()), |acc, k| acc.add(&self.get(i, k).mul(&other.get(k, j))))
+            })
+            .collect();
+        MatrixElement { entries, dim: n }
+    }
+}
+
+// Implementation for the primary GROUP properties (always additive for rings)
+impl GroupElement for MatrixElement {
+    fn op(&self, other: &Self) -> Self {
+        self.add(other)
+    }
+
+    fn inverse(&self) -> Self {
+        self.negate()
+    }
+}
+
+impl CanonicalRepr for MatrixElement {
+    /// The dimension followed by the bytes of each entry, row by row.
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        let mut bytes = (self.dim as u64).to_be_bytes().to_vec();
+        for entry in &self.entries {
+            bytes.extend(entry.to_canonical_bytes());
+        }
+        bytes
+    }
+}
+
+impl fmt::Display for MatrixElement {
+    /// Prints the rows, e.g. `[[1, 2], [3, 4]] (mod 5)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<String> = self.entries
+            .chunks(self.dim)
+            .map(|row| {
+                let values: Vec<String> = row.iter().map(|a| a.value().to_string()).collect();
+                format!("[{}]", values.join(", "))
+            })
+            .collect();
+        write!(f, "[{}] (mod {})", rows.join(", "), self.modulus())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rings::FiniteRing;
+
+    #[test]
+    fn test_matrix_ring_creation() {
+        let ring = MatrixRing::new(2, 5).unwrap();
+        assert_eq!(ring.dim(), 2);
+        assert_eq!(ring.modulus(), 5);
+        assert_eq!(*ring.one().unwrap(), ring.element(&[vec![1, 0], vec![0, 1]]).unwrap());
+        assert_eq!(*ring.zero(), ring.element(&[vec![5, 0], vec![0, 10]]).unwrap());
+        assert!(ring.element(&[vec![1, 0]]).is_err());
+        assert!(MatrixRing::new(0, 5).is_err());
+        assert!(MatrixRing::new(2, 0).is_err());
+        assert_eq!(MatrixRing::new(2, 2).unwrap().elements().len(), 16);
+    }
+
+    #[test]
+    fn test_matrix_arithmetic() {
+        let ring = MatrixRing::new(2, 5).unwrap();
+        let a = ring.element(&[vec![1, 2], vec![3, 4]]).unwrap();
+        let b = ring.element(&[vec![0, 1], vec![1, 0]]).unwrap();
+        assert_eq!(a.mul(&b), ring.element(&[vec![2, 1], vec![4, 3]]).unwrap());
+        assert_eq!(b.mul(&a), ring.element(&[vec![3, 4], vec![1, 2]]).unwrap());
+        assert_eq!(a.add(&a.negate()), *ring.zero());
+        assert_eq!(a.mul(ring.one().unwrap()), a);
+        assert_eq!(format!("{}", a), "[[1, 2], [3, 4]] (mod 5)");
+    }
+
+    #[test]
+    fn test_matrix_determinant() {
+        let ring = MatrixRing::new(3, 7).unwrap();
+        let a = ring.element(&[vec![2, 0, 1], vec![1, 3, 2], vec![1, 1, 1]]).unwrap();
+        // 2 * (3 - 2) - 0 + 1 * (1 - 3) = 0
+        assert_eq!(a.determinant().value(), 0);
+        assert!(!a.is_invertible());
+        assert_eq!(ring.one().unwrap().determinant().value(), 1);
+
+        // det(2I) = 4 is not a unit mod 6
+        let ring = MatrixRing::new(2, 6).unwrap();
+        assert!(!ring.element(&[vec![2, 0], vec![0, 2]]).unwrap().is_invertible());
+        assert!(ring.element(&[vec![1, 1], vec![0, 5]]).unwrap().is_invertible());
+    }
+
+    #[test]
+    fn test_matrix_ring_is_not_commutative() {
+        let matrices = MatrixRing::new(2, 2).unwrap();
+        assert!(!matrices.is_commutative());
+        let ring = FiniteRing::try_new_with_identities(
+            matrices.elements(),
+            matrices.zero().clone(),
+            matrices.one().cloned(),
+        ).unwrap();
+        assert!(!ring.is_commutative());
+        // GL_2(F_2) has order 6
+        assert_eq!(ring.units().len(), 6);
+        assert_eq!(matrices.elements().iter().filter(|m| m.is_invertible()).count(), 6);
+    }
+}
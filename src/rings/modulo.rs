@@ -1,7 +1,7 @@
 use crate::error::AbsaglError;
 use crate::groups::{GroupElement, CanonicalRepr, CheckedOp};
 use crate::groups::modulo::ModuloError;
-use crate::rings::{RingElement, CheckedRingOp};
+use crate::rings::{RingElement, CheckedRingOp, MulInverse};
 use crate::utils;
 use std::fmt;
 
@@ -11,11 +11,30 @@ use std::fmt;
 /// negation, and multiplication, as well as checked operations that ensure
 /// the validity of the operations (e.g., checking for zero divisors).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawModuloElement"))]
 pub struct ModuloElement {
     value: u64,
     modulus: u64,
 }
 
+/// The serialized form of a `ModuloElement`, checked by `ModuloElement::try_new` when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawModuloElement {
+    value: u64,
+    modulus: u64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawModuloElement> for ModuloElement {
+    type Error = AbsaglError;
+
+    fn try_from(raw: RawModuloElement) -> Result<Self, Self::Error> {
+        ModuloElement::try_new(raw.value, raw.modulus)
+    }
+}
+
 impl ModuloElement {
     /// Creates a new `ModuloElement` with the given value and modulus.
     /// If the value is greater than or equal to the modulus, it will be reduced modulo the modulus.
@@ -43,6 +62,23 @@ impl ModuloElement {
         Ok(Self { value, modulus })
     }
 
+    /// Raises the element to the power `exp` by modular exponentiation (square and multiply),
+    /// with u128 intermediates so large moduli don't overflow. `exp == 0` gives `1 (mod n)`.
+    pub fn pow(&self, exp: u64) -> Self {
+        let n = self.modulus as u128;
+        let mut result = 1 % n;
+        let mut base = self.value as u128 % n;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = result * base % n;
+            }
+            base = base * base % n;
+            exp /= 2;
+        }
+        Self { value: result as u64, modulus: self.modulus }
+    }
+
     /// Generates a vector of `ModuloElement` instances representing the group of integers modulo `modulus`.
     /// This function creates elements from 0 to `modulus - 1`, each with the specified modulus.
     /// Returns an empty vector if the modulus is zero.
@@ -72,11 +108,21 @@ impl RingElement for ModuloElement {
         if self.modulus != other.modulus {
             panic!("Cannot multiply elements with different moduli");
         }
-        Self {value:(self.value * other.value) % self.modulus, modulus: self.modulus}
+        // use a u128 intermediate so moduli above 2^32 don't overflow
+        let value = ((self.value as u128 * other.value as u128) % self.modulus as u128) as u64;
+        Self {value, modulus: self.modulus}
     }
 }
 
 
+impl MulInverse for ModuloElement {
+    /// The inverse exists iff gcd(value, modulus) = 1.
+    fn mul_inverse(&self) -> Option<Self> {
+        utils::modular_inverse(self.value as i64, self.modulus as i64)
+            .map(|inv| Self { value: inv as u64, modulus: self.modulus })
+    }
+}
+
 impl CheckedRingOp for ModuloElement {
     type Error = ModuloError;
 
@@ -184,6 +230,38 @@ mod tests {
         assert_eq!(result.value(), 3); // (5 * 3) % 12 = 3
     }
 
+    #[test]
+    fn test_modulo_element_multiplication_large_modulus() {
+        let modulus = (1u64 << 33) + 17;
+        let elem1 = ModuloElement::new((1 << 33) + 5, modulus);
+        let elem2 = ModuloElement::new((1 << 32) + 3, modulus);
+        assert_eq!(elem1.mul(&elem2).value(), 66);
+    }
+
+    #[test]
+    fn test_modulo_element_pow() {
+        assert_eq!(ModuloElement::new(3, 7).pow(0).value(), 1);
+        assert_eq!(ModuloElement::new(3, 7).pow(6).value(), 1);
+        assert_eq!(ModuloElement::new(2, 12).pow(5).value(), 8);
+        assert_eq!(ModuloElement::new(5, 1).pow(0).value(), 0);
+        // agrees with repeated mul
+        let a = ModuloElement::new(5, 13);
+        let mut acc = ModuloElement::new(1, 13);
+        for exp in 0..20 {
+            assert_eq!(a.pow(exp), acc);
+            acc = acc.mul(&a);
+        }
+        // large modulus doesn't overflow, 2^64 mod (2^61 - 1) = 8
+        let modulus = (1u64 << 61) - 1;
+        assert_eq!(ModuloElement::new(2, modulus).pow(64).value(), 8);
+    }
+
+    #[test]
+    fn test_modulo_element_mul_inverse() {
+        assert_eq!(ModuloElement::new(3, 7).mul_inverse(), Some(ModuloElement::new(5, 7)));
+        assert_eq!(ModuloElement::new(4, 12).mul_inverse(), None);
+    }
+
     #[test]
     fn test_modulo_element_checked_addition() {
         let elem1 = ModuloElement::new(5, 12);
@@ -236,4 +314,18 @@ mod tests {
         let elem = ModuloElement::new(5, 12);
         assert_eq!(format!("{}", elem), "5 (mod 12)");
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_modulo_element_serde_round_trip() {
+        let elem = ModuloElement::new(5, 12);
+        let json = serde_json::to_string(&elem).unwrap();
+        assert_eq!(serde_json::from_str::<ModuloElement>(&json).unwrap(), elem);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_modulo_element_serde_rejects_invalid() {
+        assert!(serde_json::from_str::<ModuloElement>(r#"{"value":3,"modulus":0}"#).is_err());
+    }
+}
@@ -0,0 +1,231 @@
+use crate::error::AbsaglError;
+use crate::groups::{GroupElement, CanonicalRepr};
+use crate::rings::{RingElement, RingError, MulInverse};
+
+
+/// Represents a polynomial `c_0 + c_1 x + ... + c_n x^n` with coefficients in a ring R, e.g. `F_p[x]`.
+/// the coefficients are stored from the constant term up, and trailing zeros are always trimmed,
+/// so the zero polynomial has no coefficients and two equal polynomials have equal vectors.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polynomial<R: RingElement> {
+    coefficients: Vec<R>,
+}
+
+/// an element is zero iff `c + c == c`, this avoids needing a zero constructor on `RingElement`
+fn is_zero<R: RingElement>(c: &R) -> bool {
+    c.add(c) == *c
+}
+
+impl<R: RingElement> Polynomial<R> {
+
+    /// Creates a new polynomial from its coefficients, `coefficients[i]` is the coefficient of `x^i`.
+    /// trailing zero coefficients are removed.
+    pub fn new(mut coefficients: Vec<R>) -> Self {
+        while coefficients.last().is_some_and(is_zero) {
+            coefficients.pop();
+        }
+        Polynomial { coefficients }
+    }
+
+    /// Returns the zero polynomial
+    pub fn zero() -> Self {
+        Polynomial { coefficients: Vec::new() }
+    }
+
+    /// Returns the coefficients, from the constant term up
+    pub fn coefficients(&self) -> &[R] {
+        &self.coefficients
+    }
+
+    /// Returns the degree of the polynomial, or `None` for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// Returns the coefficient of the highest power, or `None` for the zero polynomial
+    pub fn leading_coefficient(&self) -> Option<&R> {
+        self.coefficients.last()
+    }
+
+    /// Returns whether this is the zero polynomial
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's method.
+    pub fn evaluate(&self, x: &R) -> R {
+        let zero = x.add(&x.negate());
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(zero, |acc, c| acc.mul(x).add(c))
+    }
+
+    /// Polynomial long division, returns `(quotient, remainder)` with `self = quotient * divisor + remainder`
+    /// and `deg(remainder) < deg(divisor)`.
+    /// returns `RingError::NotInvertible` if the divisor is zero or its leading coefficient is not a unit.
+    pub fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), AbsaglError>
+    where
+        R: MulInverse,
+    {
+        let lead_inverse = match divisor.leading_coefficient().and_then(|c| c.mul_inverse()) {
+            Some(inv) => inv,
+            None => {
+                log::error!("The leading coefficient of the divisor is not invertible");
+                return Err(RingError::NotInvertible)?;
+            }
+        };
+        let divisor_degree = divisor.coefficients.len() - 1;
+        let zero = lead_inverse.add(&lead_inverse.negate());
+
+        let mut quotient = vec![zero.clone(); self.coefficients.len().saturating_sub(divisor_degree)];
+        let mut remainder = self.clone();
+        while let Some(degree) = remainder.degree() {
+            if degree < divisor_degree {
+                break;
+            }
+            // cancel the leading term of the remainder with coef * x^shift * divisor
+            let coef = remainder.coefficients[degree].mul(&lead_inverse);
+            let shift = degree - divisor_degree;
+            let mut term = vec![zero.clone(); shift];
+            term.extend(divisor.coefficients.iter().map(|c| coef.mul(c)));
+            quotient[shift] = coef;
+            remainder = remainder.add(&Polynomial::new(term).negate());
+        }
+        Ok((Polynomial::new(quotient), remainder))
+    }
+}
+
+impl<R: RingElement> RingElement for Polynomial<R> {
+    /// Adds the coefficients of equal powers.
+    fn add(&self, other: &Self) -> Self {
+        let (long, short) = if self.coefficients.len() >= other.coefficients.len() {
+            (&self.coefficients, &other.coefficients)
+        } else {
+            (&other.coefficients, &self.coefficients)
+        };
+        let coefficients = long
+            .iter()
+            .enumerate()
+            .map(|(i, c)| match short.get(i) {
+                Some(d) => c.add(d),
+                None => c.clone(),
+            })
+            .collect();
+        Polynomial::new(coefficients)
+    }
+
+    fn negate(&self) -> Self {
+        Polynomial { coefficients: self.coefficients.iter().map(|c| c.negate()).collect() }
+    }
+
+    /// Multiplies by convolution of the coefficients, `(fg)_k = sum_{i+j=k} f_i g_j`.
+    fn mul(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Polynomial::zero();
+        }
+        let mut coefficients: Vec<Option<R>> = vec![None; self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in other.coefficients.iter().enumerate() {
+                let product = a.mul(b);
+                coefficients[i + j] = Some(match &coefficients[i + j] {
+                    Some(acc) => acc.add(&product),
+                    None => product,
+                });
+            }
+        }
+        // every power 0..=deg(f)+deg(g) is reached by some i + j
+        Polynomial::new(coefficients.into_iter().flatten().collect())
+    }
+}
+
+// Implementation for the primary GROUP properties (always additive for rings)
+impl<R: RingElement> GroupElement for Polynomial<R> {
+    fn op(&self, other: &Self) -> Self {
+        self.add(other)
+    }
+
+    fn inverse(&self) -> Self {
+        self.negate()
+    }
+}
+
+impl<R: RingElement + CanonicalRepr> CanonicalRepr for Polynomial<R> {
+    /// The number of coefficients followed by the bytes of each coefficient.
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        let mut bytes = (self.coefficients.len() as u64).to_be_bytes().to_vec();
+        for c in &self.coefficients {
+            bytes.extend(c.to_canonical_bytes());
+        }
+        bytes
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rings::modulo::ModuloElement;
+
+    /// builds a polynomial over F_p from its integer coefficients
+    fn poly(coefficients: &[u64], p: u64) -> Polynomial<ModuloElement> {
+        Polynomial::new(coefficients.iter().map(|&c| ModuloElement::new(c, p)).collect())
+    }
+
+    #[test]
+    fn test_polynomial_normalization_and_degree() {
+        assert_eq!(poly(&[1, 2, 0, 0], 5), poly(&[1, 2], 5));
+        assert_eq!(poly(&[1, 2, 5], 5).degree(), Some(1));
+        assert_eq!(poly(&[0, 0], 5), Polynomial::zero());
+        assert_eq!(Polynomial::<ModuloElement>::zero().degree(), None);
+    }
+
+    #[test]
+    fn test_polynomial_add_and_mul() {
+        // (x + 1)^2 = x^2 + 1 over F_2
+        let x_plus_1 = poly(&[1, 1], 2);
+        assert_eq!(x_plus_1.mul(&x_plus_1), poly(&[1, 0, 1], 2));
+        assert_eq!(x_plus_1.add(&x_plus_1), Polynomial::zero());
+
+        // (2x + 1)(x + 3) = 2x^2 + 2x + 3 over F_5
+        assert_eq!(poly(&[1, 2], 5).mul(&poly(&[3, 1], 5)), poly(&[3, 2, 2], 5));
+        assert_eq!(poly(&[1, 2], 5).add(&poly(&[4, 0, 1], 5)), poly(&[0, 2, 1], 5));
+        assert_eq!(poly(&[1, 2], 5).mul(&Polynomial::zero()), Polynomial::zero());
+    }
+
+    #[test]
+    fn test_polynomial_evaluate() {
+        // x^2 + x + 1 over F_2 has no roots
+        let f = poly(&[1, 1, 1], 2);
+        assert_eq!(f.evaluate(&ModuloElement::new(0, 2)).value(), 1);
+        assert_eq!(f.evaluate(&ModuloElement::new(1, 2)).value(), 1);
+
+        // 3x^2 + 2 at x = 4 over F_7: 3 * 16 + 2 = 50 = 1
+        assert_eq!(poly(&[2, 0, 3], 7).evaluate(&ModuloElement::new(4, 7)).value(), 1);
+    }
+
+    #[test]
+    fn test_polynomial_div_rem() {
+        // x^3 + 2x + 1 = (x^2 + 3x + 1)(x + 2) + 4 over F_5
+        let f = poly(&[1, 2, 0, 1], 5);
+        let g = poly(&[2, 1], 5);
+        let (q, r) = f.div_rem(&g).unwrap();
+        assert_eq!(q, poly(&[1, 3, 1], 5));
+        assert_eq!(r, poly(&[4], 5));
+        assert_eq!(q.mul(&g).add(&r), f);
+
+        // non-monic divisor
+        let g = poly(&[1, 0, 3], 5);
+        let (q, r) = f.div_rem(&g).unwrap();
+        assert!(r.degree().unwrap_or(0) < 2);
+        assert_eq!(q.mul(&g).add(&r), f);
+
+        // dividing by a polynomial of higher degree leaves it as the remainder
+        let (q, r) = g.div_rem(&f).unwrap();
+        assert_eq!((q, r), (Polynomial::zero(), g));
+
+        assert!(f.div_rem(&Polynomial::zero()).is_err());
+        // 2 is not invertible mod 4
+        assert!(poly(&[1, 1], 4).div_rem(&poly(&[1, 2], 4)).is_err());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::groups::{GroupElement, CanonicalRepr};
+use crate::rings::RingElement;
+use std::fmt;
+use std::sync::Arc;
+
+
+/// An element `a + I` of a quotient ring R/I, the additive coset of a two-sided ideal I.
+/// the representative is always the element of the coset with the smallest canonical bytes,
+/// so two elements are equal iff they are the same coset, and `Eq`/`Hash` can be derived.
+/// the ideal is shared between all elements of the same quotient ring, behind an `Arc`
+/// since group elements must be `Sync`, hence the extra `Send` bound on the element impls.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QuotientElement<T: RingElement + CanonicalRepr> {
+    representative: T,
+    ideal: Arc<Vec<T>>,
+}
+
+impl<T: RingElement + CanonicalRepr> QuotientElement<T> {
+
+    /// Creates the coset `a + I`. the ideal is expected to be sorted by canonical bytes,
+    /// as `FiniteRing::quotient` does, otherwise equal cosets may compare unequal.
+    pub(crate) fn new(a: T, ideal: Arc<Vec<T>>) -> Self {
+        let representative = ideal
+            .iter()
+            .map(|i| a.add(i))
+            .min_by(|x, y| x.to_canonical_bytes().cmp(&y.to_canonical_bytes()))
+            .unwrap_or(a);
+        QuotientElement { representative, ideal }
+    }
+
+    /// Returns the canonical representative of the coset
+    pub fn representative(&self) -> &T {
+        &self.representative
+    }
+
+    /// Returns the ideal I
+    pub fn ideal(&self) -> &[T] {
+        &self.ideal
+    }
+}
+
+impl<T: RingElement + CanonicalRepr + Send> RingElement for QuotientElement<T> {
+    /// (a + I) + (b + I) = (a + b) + I
+    fn add(&self, other: &Self) -> Self {
+        assert_eq!(self.ideal, other.ideal, "Cannot add cosets of different ideals");
+        Self::new(self.representative.add(&other.representative), self.ideal.clone())
+    }
+
+    fn negate(&self) -> Self {
+        Self::new(self.representative.negate(), self.ideal.clone())
+    }
+
+    /// (a + I)(b + I) = ab + I, well defined since I is a two-sided ideal
+    fn mul(&self, other: &Self) -> Self {
+        assert_eq!(self.ideal, other.ideal, "Cannot multiply cosets of different ideals");
+        Self::new(self.representative.mul(&other.representative), self.ideal.clone())
+    }
+}
+
+// Implementation for the primary GROUP properties (always additive for rings)
+impl<T: RingElement + CanonicalRepr + Send> GroupElement for QuotientElement<T> {
+    fn op(&self, other: &Self) -> Self {
+        self.add(other)
+    }
+
+    fn inverse(&self) -> Self {
+        self.negate()
+    }
+}
+
+impl<T: RingElement + CanonicalRepr> CanonicalRepr for QuotientElement<T> {
+    /// cosets of the same quotient share the ideal, so the representative identifies the coset.
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        self.representative.to_canonical_bytes()
+    }
+}
+
+impl<T: RingElement + CanonicalRepr + fmt::Display> fmt::Display for QuotientElement<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + I", self.representative)
+    }
+}
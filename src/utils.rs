@@ -16,13 +16,30 @@ pub fn is_mapping_valid(mapping: &[usize]) -> bool {
 }
 
 /// Computes the greatest common divisor (GCD) of two numbers using the Euclidean algorithm.
+/// this is a thin wrapper over `gcd_u64`.
 pub fn gcd(a: usize, b: usize) -> usize {
-    if b == 0 { a } else { gcd(b, a % b) }
+    gcd_u64(a as u64, b as u64) as usize
 }
 
 /// Computes the least common multiple (LCM) of two numbers using the GCD.
+/// this is a thin wrapper over `lcm_u64`.
 pub fn lcm(a: usize, b: usize) -> usize {
-    if a == 0 || b == 0 { 0 } else { (a * b) / gcd(a, b) }
+    lcm_u64(a as u64, b as u64) as usize
+}
+
+/// Computes the greatest common divisor (GCD) of two `u64` using the Euclidean algorithm,
+/// this avoids truncating moduli on 32-bit targets where `usize` is smaller than `u64`.
+pub fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Computes the least common multiple (LCM) of two `u64`,
+/// divides before multiplying so the intermediate never exceeds the result.
+pub fn lcm_u64(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 { 0 } else { a / gcd_u64(a, b) * b }
 }
 
 
@@ -76,7 +93,7 @@ pub fn prime_factorization(mut n: u64) -> Vec<(u64, u32)> {
     let mut d = 2;
     while d * d <= n {
         let mut count = 0;
-        while n % d == 0 {
+        while n.is_multiple_of(d) {
             count += 1;
             n /= d;
         }
@@ -91,6 +108,123 @@ pub fn prime_factorization(mut n: u64) -> Vec<(u64, u32)> {
     factors
 }
 
+/// Computes Euler's totient φ(n), the number of integers in `1..=n` coprime to n, from the prime factorization.
+/// φ(p^k) = (p - 1) p^(k-1) and φ is multiplicative, by convention φ(0) = 0 and φ(1) = 1.
+pub fn euler_totient(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    prime_factorization(n)
+        .iter()
+        .fold(1, |acc, &(p, k)| acc * (p - 1) * p.pow(k - 1))
+}
+
+/// Solves the system `x ≡ a_i (mod m_i)` given as pairs `(a_i, m_i)` with the Chinese Remainder Theorem.
+/// the moduli need not be pairwise coprime, as long as the congruences are consistent.
+/// Returns the smallest non-negative solution modulo the lcm of the moduli,
+/// or `None` if the system is inconsistent, a modulus is not positive, or the lcm does not fit in i64.
+pub fn crt(residues: &[(i64, i64)]) -> Option<i64> {
+    // (x, m) is the solution of the congruences merged so far
+    let mut x: i128 = 0;
+    let mut m: i128 = 1;
+    for &(a, n) in residues {
+        if n <= 0 {
+            return None;
+        }
+        let (a, n) = (a as i128, n as i128);
+        // solve x + m*t ≡ a (mod n), i.e. m*t ≡ a - x (mod n)
+        let (g, u, _) = extended_gcd(m as i64, n as i64);
+        let (g, u) = (g as i128, u as i128);
+        if (a - x) % g != 0 {
+            return None;
+        }
+        let step = n / g;
+        let t = ((a - x) / g % step * u % step + step) % step;
+        x += m * t;
+        m *= step;
+        if m > i64::MAX as i128 {
+            return None;
+        }
+        x = x.rem_euclid(m);
+    }
+    Some(x as i64)
+}
+
+/// Computes `a * b mod m` with a u128 intermediate so it never overflows.
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+/// Computes `base^exp mod m` by squaring.
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut res = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            res = mul_mod(res, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp /= 2;
+    }
+    res
+}
+
+/// Computes the Legendre symbol (a/p) for an odd prime `p` using Euler's criterion `a^((p-1)/2) mod p`.
+/// Returns `Some(0)` if p divides a, `Some(1)` if a is a quadratic residue mod p, and `Some(-1)` otherwise.
+/// For p = 2 every odd a is a residue. Returns `None` if `p` is not a prime.
+pub fn legendre_symbol(a: u64, p: u64) -> Option<i8> {
+    if p < 2 || prime_factorization(p) != vec![(p, 1)] {
+        return None;
+    }
+    let a = a % p;
+    if a == 0 {
+        return Some(0);
+    }
+    if p == 2 {
+        return Some(1);
+    }
+    Some(if pow_mod(a, (p - 1) / 2, p) == 1 { 1 } else { -1 })
+}
+
+/// Computes a square root of `a` modulo a prime `p` with the Tonelli–Shanks algorithm.
+/// Returns `Some(x)` with `x * x ≡ a (mod p)` (the other root is `p - x`),
+/// or `None` if a is a non-residue or `p` is not a prime.
+pub fn sqrt_mod(a: u64, p: u64) -> Option<u64> {
+    // the Legendre symbol also rejects p < 2 and composite p, on which the loop below would not terminate
+    if legendre_symbol(a, p)? == -1 {
+        return None;
+    }
+    let a = a % p;
+    if a == 0 || p == 2 {
+        return Some(a);
+    }
+
+    // write p - 1 = q * 2^s with q odd
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    // find a non-residue z
+    let z = (2..p).find(|&z| legendre_symbol(z, p) == Some(-1))?;
+
+    let mut m = s;
+    let mut c = pow_mod(z, q, p);
+    let mut t = pow_mod(a, q, p);
+    let mut r = pow_mod(a, q.div_ceil(2), p);
+    while t != 1 {
+        // find the least i with t^(2^i) = 1
+        let mut i = 0;
+        let mut t2 = t;
+        while t2 != 1 {
+            t2 = mul_mod(t2, t2, p);
+            i += 1;
+        }
+        let b = pow_mod(c, 1u64 << (m - i - 1), p);
+        m = i;
+        c = mul_mod(b, b, p);
+        t = mul_mod(t, c, p);
+        r = mul_mod(r, b, p);
+    }
+    Some(r)
+}
 
 /// A macro to mimic a notebook's "In/Out" cells for easy documentation.
 ///
@@ -126,8 +260,20 @@ mod tests {
 
     #[test]
     fn test_gcd() {
-        let result = gcd(2024 as usize, 748 as usize);
-        assert_eq!(result, 44 as usize)
+        let result = gcd(2024_usize, 748_usize);
+        assert_eq!(result, 44_usize)
+    }
+
+    #[test]
+    fn test_gcd_lcm_u64() {
+        assert_eq!(gcd_u64(2024, 748), 44);
+        assert_eq!(gcd_u64(0, 5), 5);
+        assert_eq!(lcm_u64(4, 6), 12);
+        assert_eq!(lcm_u64(0, 6), 0);
+        // a * b overflows u64, but the lcm itself fits
+        let big = 1u64 << 62;
+        assert_eq!(lcm_u64(big, big / 2), big);
+        assert_eq!(lcm(4, 6), 12);
     }
 
     #[test]
@@ -136,6 +282,58 @@ mod tests {
         assert_eq!(result, Some(4)); // 3 * 4 % 11 == 1
     }
 
+    #[test]
+    fn test_euler_totient() {
+        assert_eq!(euler_totient(1), 1);
+        assert_eq!(euler_totient(7), 6);
+        assert_eq!(euler_totient(12), 4);
+        assert_eq!(euler_totient(36), 12);
+    }
+
+    #[test]
+    fn test_crt() {
+        // x ≡ 2 (mod 3), x ≡ 3 (mod 5), x ≡ 2 (mod 7)
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some(23));
+        // non-coprime but consistent moduli
+        assert_eq!(crt(&[(3, 4), (1, 6)]), Some(7));
+        // inconsistent
+        assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+        assert_eq!(crt(&[(-1, 5)]), Some(4));
+        assert_eq!(crt(&[]), Some(0));
+    }
+
+    #[test]
+    fn test_legendre_symbol() {
+        assert_eq!(legendre_symbol(2, 7), Some(1)); // 3² = 2 mod 7
+        assert_eq!(legendre_symbol(3, 7), Some(-1));
+        assert_eq!(legendre_symbol(14, 7), Some(0));
+        // non-prime moduli
+        for p in [0, 1, 9, 15] {
+            assert_eq!(legendre_symbol(8, p), None);
+        }
+    }
+
+    #[test]
+    fn test_sqrt_mod() {
+        // p = 17 has p - 1 = 2^4, the hardest case for Tonelli–Shanks
+        for p in [7u64, 13, 17, 41, 1_000_000_007] {
+            for a in 0..50 {
+                match sqrt_mod(a, p) {
+                    Some(x) => assert_eq!(mul_mod(x, x, p), a % p),
+                    None => assert_eq!(legendre_symbol(a, p), Some(-1)),
+                }
+            }
+        }
+        assert_eq!(sqrt_mod(3, 7), None);
+        assert_eq!(sqrt_mod(1, 2), Some(1));
+        // non-prime moduli return None instead of panicking or looping
+        assert_eq!(sqrt_mod(8, 0), None);
+        assert_eq!(sqrt_mod(8, 9), None);
+        assert_eq!(sqrt_mod(4, 9), None);
+        assert_eq!(sqrt_mod(4, 15), None);
+        assert_eq!(sqrt_mod(0, 15), None);
+    }
+
     #[test]
     fn test_prime_factorization() {
         let result = prime_factorization(12);
@@ -1,19 +1,7 @@
 
-use absagl::groups::permutation::Permutation;
 use absagl::groups::permutation::SparsePerm;
 use absagl::groups::GroupElement;
 
-///
-/// 
-/// 
-/// 
-/// 
-/// 
-/// 
-/// 
-/// 
-/// 
-
 
 // add test hash to each test mod
 // use std::collections::hash_map::DefaultHasher;
@@ -118,11 +106,11 @@ mod test_sparse_permutation {
         // The result should be the identity permutation
         let mut identitical = true;
         for i in 0..b.mapping.len() {
-            if b.mapping.get(&(i as usize)) != Some(&(i as usize)) {
-                println!("Expected {} but got {:?}", i, b.mapping.get(&(i as usize)));
+            if b.mapping.get(&i) != Some(&i) {
+                println!("Expected {} but got {:?}", i, b.mapping.get(&i));
                 identitical = false;
             }
         }
-        assert_eq!(identitical, true);
+        assert!(identitical);
     }
 }
\ No newline at end of file